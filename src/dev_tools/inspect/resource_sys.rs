@@ -50,6 +50,10 @@ pub fn debug_resource_plugin(app: &mut App) {
         ResourceInspectorPlugin::<UnitStatsCache>::default()
             .run_if(command_key_toggle_active(false, KeyCode::Digit3)),
     );
+    app.add_plugins(
+        ResourceInspectorPlugin::<SellRefund>::default()
+            .run_if(command_key_toggle_active(false, KeyCode::Digit3)),
+    );
     // app.add_plugins(
     //     ResourceInspectorPlugin::<FadeOverlay>::default()
     //         .run_if(command_key_toggle_active(false, KeyCode::Digit3)),
@@ -4,6 +4,7 @@ use bevy::{dev_tools::states::log_transitions, prelude::*};
 
 pub(crate) mod log_components;
 mod validate_preloading;
+mod validate_balance;
 
 mod print_log;
 use print_log::*;
@@ -52,7 +53,11 @@ pub(super) fn plugin(app: &mut App) {
         (log_transitions::<Menu>, log_transitions::<LoadingScreen>).chain(),
     );
 
-    app.add_plugins((validate_preloading::plugin, log_components::plugin));
+    app.add_plugins((
+        validate_preloading::plugin,
+        validate_balance::plugin,
+        log_components::plugin,
+    ));
     // app.add_systems(Update, _print_hover_on_click);
 }
 pub fn command_key_toggle_active(
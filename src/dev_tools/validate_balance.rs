@@ -0,0 +1,85 @@
+//! Lists balance sheet problems on the loading screen.
+//!
+//! A sheet that fails to parse otherwise leaves the loading screen stuck without a word, and
+//! dev builds stay on the level loading screen until every [`BalanceIssue`] is fixed.
+
+use bevy::{asset::LoadState, ui::Val::*};
+use bevy_common_assets::csv::LoadedCsv;
+
+use crate::{prelude::*, screens::Screen, theme::prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        show_balance_issues.run_if(in_state(Screen::Loading)),
+    );
+}
+
+#[derive(Component)]
+struct BalanceIssuesLabel;
+
+fn show_balance_issues(
+    mut commands: Commands,
+    balance_issues: Res<BalanceIssues>,
+    asset_server: Res<AssetServer>,
+    palette: Res<ColorPalette>,
+    mut q_label: Query<&mut Text, With<BalanceIssuesLabel>>,
+) {
+    let mut lines: Vec<String> = [
+        load_failure::<UnitRow>(&asset_server, UNIT_SHEET),
+        load_failure::<MemoryRow>(&asset_server, MEMORY_SHEET),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    lines.extend(balance_issues.0.iter().map(ToString::to_string));
+
+    let text = if lines.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "Balance sheet problems (save the sheet to reload):\n{}",
+            lines.join("\n")
+        )
+    };
+
+    if let Ok(mut label) = q_label.single_mut() {
+        if label.0 != text {
+            label.0 = text;
+        }
+        return;
+    }
+    if text.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Name::new("Balance Issues"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Px(20.0),
+            right: Px(20.0),
+            bottom: Px(20.0),
+            ..default()
+        },
+        GlobalZIndex(1),
+        DespawnOnExit(Screen::Loading),
+        children![(
+            widget::label_small(text, &palette, &asset_server),
+            BalanceIssuesLabel
+        )],
+    ));
+}
+
+/// The load error of `balance/{sheet}`, if it failed to parse.
+fn load_failure<R>(asset_server: &AssetServer, sheet: &str) -> Option<String>
+where
+    R: for<'de> serde::Deserialize<'de> + Asset,
+{
+    let path = format!("balance/{sheet}");
+    let handle = asset_server.get_handle::<LoadedCsv<R>>(&path)?;
+    match asset_server.get_load_state(handle.id())? {
+        LoadState::Failed(error) => Some(error.to_string()),
+        _ => None,
+    }
+}
@@ -13,6 +13,10 @@ pub struct MainMesh;
 #[derive(Component, Default)]
 pub struct RequiredAseprite;
 
+/// Image name under `procreate/` for actors that are not spawned from a prefab.
+#[derive(Component)]
+pub struct ActorSprite(pub String);
+
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.add_observer(setup_mesh);
     app.add_systems(Update, setup_sprite);
@@ -22,6 +26,7 @@ pub fn setup_sprite(
     q_actor: Query<Entity, With<RequiredAseprite>>,
     q_belong_to: Query<(Entity, &BelongTo), With<MainMesh>>,
     q_prefab: Query<&PrefabId>,
+    q_sprite: Query<&ActorSprite>,
     q_enemy: Query<(), With<EnemyFaction>>,
     mut commands: Commands,
     server: Res<AssetServer>,
@@ -30,7 +35,11 @@ pub fn setup_sprite(
         let Ok(actor) = q_actor.get(belong_to.0) else {
             continue;
         };
-        let Ok(prefab_id) = q_prefab.get(actor) else {
+        let name = if let Ok(sprite) = q_sprite.get(actor) {
+            sprite.0.clone()
+        } else if let Ok(prefab_id) = q_prefab.get(actor) {
+            prefab_id.id.to_string()
+        } else {
             continue;
         };
 
@@ -39,7 +48,7 @@ pub fn setup_sprite(
         } else {
             ""
         };
-        let path = format!("procreate/{}{}.png", prefab_id.id, postfix);
+        let path = format!("procreate/{}{}.png", name, postfix);
         commands.entity(main_mesh).insert((
             Pickable::default(),
             Sprite::from_image(server.load(path)),
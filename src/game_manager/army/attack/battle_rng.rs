@@ -0,0 +1,26 @@
+use bevy_rand::prelude::ChaCha8Rng;
+use rand::SeedableRng;
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.init_resource::<BattleRng>();
+}
+
+/// Random stream for every roll that can change a fight's outcome.
+/// Kept apart from the `GlobalRng` so cosmetic draws (shadows, corpses, sfx pitch)
+/// don't shift it; reseeded at the start of each battle so the fight can be replayed.
+#[derive(Resource, Deref, DerefMut)]
+pub struct BattleRng(ChaCha8Rng);
+
+impl BattleRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self(ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl Default for BattleRng {
+    fn default() -> Self {
+        Self::from_seed(0)
+    }
+}
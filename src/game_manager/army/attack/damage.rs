@@ -0,0 +1,225 @@
+use std::fmt;
+
+use crate::prelude::*;
+
+/// Bonus for hitting the unit kind you counter.
+const COUNTER_MULTIPLIER: f32 = 1.2;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.init_resource::<DamageFormula>();
+}
+
+// ============================================================================
+// Damage types
+// ============================================================================
+
+/// What kind of harm an attack does. Units resist each kind separately.
+#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum DamageType {
+    #[default]
+    Slash,
+    Pierce,
+    Blunt,
+    /// Memories: nightmares and waking light. Armor doesn't stop it.
+    Dream,
+}
+
+/// Share of each damage type a unit shrugs off: `0.25` takes 25% less, `-0.1` takes 10% more.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+pub struct Resistances {
+    pub slash: f32,
+    pub pierce: f32,
+    pub blunt: f32,
+    pub dream: f32,
+}
+
+impl Resistances {
+    pub fn get(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Slash => self.slash,
+            DamageType::Pierce => self.pierce,
+            DamageType::Blunt => self.blunt,
+            DamageType::Dream => self.dream,
+        }
+    }
+}
+
+impl fmt::Display for Resistances {
+    /// Only the resistances that aren't zero, e.g. `Pierce +25% Blunt -10%`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for damage_type in [
+            DamageType::Slash,
+            DamageType::Pierce,
+            DamageType::Blunt,
+            DamageType::Dream,
+        ] {
+            let resistance = self.get(damage_type);
+            if resistance == 0.0 {
+                continue;
+            }
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{damage_type:?} {:+.0}%", resistance * 100.0)?;
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Formula
+// ============================================================================
+
+/// What a [`DamageStep`] gets to look at.
+pub struct DamageContext<'a> {
+    pub damage_type: DamageType,
+    /// Stats of the attacker, if it's a unit that's still around.
+    pub attacker: Option<&'a CombatAttributes>,
+    pub target: &'a CombatAttributes,
+}
+
+/// One step of the damage formula: turns the damage so far into the damage after it.
+#[derive(Clone, Copy)]
+pub struct DamageStep {
+    /// Name in the tooltip breakdown.
+    pub label: &'static str,
+    pub apply: fn(&DamageContext, f32) -> f32,
+}
+
+/// The steps every hit goes through, in order. Plugins can add their own.
+#[derive(Resource, Clone)]
+pub struct DamageFormula {
+    pub steps: Vec<DamageStep>,
+}
+
+impl Default for DamageFormula {
+    fn default() -> Self {
+        Self {
+            steps: vec![
+                DamageStep {
+                    label: "counter",
+                    apply: counter_step,
+                },
+                DamageStep {
+                    label: "DEF",
+                    apply: defense_step,
+                },
+                DamageStep {
+                    label: "RES",
+                    apply: resistance_step,
+                },
+            ],
+        }
+    }
+}
+
+impl DamageFormula {
+    /// Runs `damage` through every step, keeping the steps that changed it.
+    pub fn compute(&self, damage: f32, context: &DamageContext) -> DamageBreakdown {
+        let mut breakdown = DamageBreakdown {
+            damage_type: context.damage_type,
+            raw: damage,
+            steps: Vec::new(),
+        };
+        let mut damage = damage;
+        for step in &self.steps {
+            let next = (step.apply)(context, damage);
+            if next != damage {
+                breakdown.steps.push((step.label, next));
+            }
+            damage = next;
+        }
+        breakdown
+    }
+}
+
+/// x1.2 against the unit kind the attacker counters.
+fn counter_step(context: &DamageContext, damage: f32) -> f32 {
+    let counters = context
+        .attacker
+        .and_then(|attacker| attacker.counter.as_ref())
+        .is_some_and(|counter| *counter == context.target.unity_kind);
+    if counters {
+        damage * COUNTER_MULTIPLIER
+    } else {
+        damage
+    }
+}
+
+/// `max(1, damage - defense)`; dream damage goes straight through armor.
+fn defense_step(context: &DamageContext, damage: f32) -> f32 {
+    match context.damage_type {
+        DamageType::Dream => damage,
+        _ => (damage - context.target.defense).max(1.0),
+    }
+}
+
+fn resistance_step(context: &DamageContext, damage: f32) -> f32 {
+    let resistance = context.target.resistances.get(context.damage_type);
+    damage * (1.0 - resistance).max(0.0)
+}
+
+/// How one hit's damage came together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageBreakdown {
+    pub damage_type: DamageType,
+    pub raw: f32,
+    /// Damage after each step that changed it.
+    pub steps: Vec<(&'static str, f32)>,
+}
+
+impl DamageBreakdown {
+    pub fn total(&self) -> f32 {
+        self.steps.last().map_or(self.raw, |(_, damage)| *damage)
+    }
+
+    pub fn push(&mut self, label: &'static str, damage: f32) {
+        self.steps.push((label, damage));
+    }
+}
+
+impl fmt::Display for DamageBreakdown {
+    /// e.g. `8 Pierce -> counter 9.6 -> DEF 5.6 -> RES 4.2`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} {:?}", self.raw, self.damage_type)?;
+        for (label, damage) in &self.steps {
+            write!(f, " -> {label} {damage:.1}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The last hit a unit took, for the tooltip.
+#[derive(Component, Debug, Clone)]
+pub struct LastHitTaken(pub DamageBreakdown);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dream_damage_ignores_defense_but_not_resistance() {
+        let mut target = CombatAttributes::melee(UnitKind::new("Shield"));
+        target.defense = 4.0;
+        target.resistances.pierce = 0.5;
+        let formula = DamageFormula::default();
+        let hit = |damage_type| {
+            formula
+                .compute(
+                    10.0,
+                    &DamageContext {
+                        damage_type,
+                        attacker: None,
+                        target: &target,
+                    },
+                )
+                .total()
+        };
+
+        assert_eq!(hit(DamageType::Slash), 6.0);
+        assert_eq!(hit(DamageType::Pierce), 3.0);
+        assert_eq!(hit(DamageType::Dream), 10.0);
+    }
+}
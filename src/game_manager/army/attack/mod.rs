@@ -1,14 +1,23 @@
-use bevy_rand::{global::GlobalRng, prelude::ChaCha8Rng};
-use rand::Rng;
-
 use crate::prelude::*;
 mod corpse;
 pub(crate) use corpse::*;
+mod battle_rng;
+pub(crate) use battle_rng::*;
+mod projectile;
+pub(crate) use projectile::*;
+mod damage;
+pub(crate) use damage::*;
 // ============================================================================
 // Plugin
 // ============================================================================
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
+    headless_plugin(app);
+    corpse::plugin(app);
+}
+
+/// Attack, damage and death systems that don't need a window or loaded assets.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
     // Configure system ordering: Attack -> TakeDamage -> Death -> DeathRecord -> DeathDespawn
     // Run after movement systems
     app.configure_sets(
@@ -26,19 +35,24 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
     );
 
     app.add_message::<TakeDamageMessage>();
+    app.add_message::<DamageDealtMessage>();
+    app.add_message::<HealMessage>();
     app.add_message::<UnitDeathMessage>();
 
     app.add_systems(
         Update,
         (
             take_damage_system.in_set(AttackSet::TakeDamage),
+            heal_system.in_set(AttackSet::TakeDamage),
             death_message_system.in_set(AttackSet::Death),
             death_despawn_system.in_set(AttackSet::DeathDespawn),
         ),
     );
     app.add_observer(been_attack);
 
-    corpse::plugin(app);
+    battle_rng::plugin(app);
+    projectile::plugin(app);
+    damage::plugin(app);
 }
 
 #[derive(Event)]
@@ -46,6 +60,7 @@ pub struct AttackEvent {
     pub _from: Entity,
     pub to: Entity,
     pub damage: f32,
+    pub damage_type: DamageType,
 }
 
 /// Event emitted when a unit dies. Used for tracking kills.
@@ -57,11 +72,12 @@ pub struct UnitDeathMessage {
 }
 
 impl AttackEvent {
-    pub fn new(from: Entity, to: Entity, damage: f32) -> Self {
+    pub fn new(from: Entity, to: Entity, damage: f32, damage_type: DamageType) -> Self {
         Self {
             _from: from,
             to,
             damage,
+            damage_type,
         }
     }
 }
@@ -71,6 +87,23 @@ pub struct TakeDamageMessage {
     pub attacker: Option<Entity>,
     pub target: Entity,
     pub damage: f32,
+    pub damage_type: DamageType,
+}
+
+/// Health a hit actually took, after the damage formula and block, and no more than
+/// the target had left.
+#[derive(Message)]
+pub struct DamageDealtMessage {
+    pub attacker: Option<Entity>,
+    pub target: Entity,
+    pub amount: f32,
+}
+
+/// Health given back to a unit, by a healer or an effect.
+#[derive(Message)]
+pub struct HealMessage {
+    pub target: Entity,
+    pub amount: f32,
 }
 
 // ============================================================================
@@ -131,74 +164,59 @@ fn death_despawn_system(q_units: Query<(Entity, &Health)>, mut commands: Command
         }
     }
 }
-fn been_attack(
-    trigger: On<AttackEvent>,
-    q_stats: Query<&CombatAttributes>,
-    q_belong: Query<&BelongToSquad>,
-    q_bonus: Query<&BigEyeDamageBonus>,
-    mut ev_damage: MessageWriter<TakeDamageMessage>,
-) {
-    let counter_mult = if let (Ok(attacker_stats), Ok(target_stats)) =
-        (q_stats.get(trigger._from), q_stats.get(trigger.to))
-    {
-        if attacker_stats.counter == Some(target_stats.unity_kind) {
-            1.2
-        } else {
-            1.0
-        }
-    } else {
-        1.0
-    };
-
-    let big_eye_mult = q_belong
-        .get(trigger._from)
-        .ok()
-        .and_then(|b| q_bonus.get(b.0).ok())
-        .map(|bonus| 1.0 + bonus.0)
-        .unwrap_or(1.0);
-
+fn been_attack(trigger: On<AttackEvent>, mut ev_damage: MessageWriter<TakeDamageMessage>) {
     ev_damage.write(TakeDamageMessage {
         attacker: Some(trigger._from),
         target: trigger.to,
-        damage: trigger.damage * counter_mult * big_eye_mult,
+        damage: trigger.damage,
+        damage_type: trigger.damage_type,
     });
 }
 
 fn take_damage_system(
     mut ev_damage: MessageReader<TakeDamageMessage>,
-    mut q_health: Query<(&mut Health, &CombatAttributes, Option<&mut ActiveBuffs>)>,
+    mut q_health: Query<(&mut Health, &CombatAttributes, Option<&mut ActiveEffects>)>,
+    q_attackers: Query<&CombatAttributes>,
+    formula: Res<DamageFormula>,
+    registry: Res<EffectRegistry>,
     q_transform: Query<&GlobalTransform>,
     mut commands: Commands,
-    mut rng: Single<&mut ChaCha8Rng, With<GlobalRng>>,
+    mut rng: ResMut<BattleRng>,
+    mut ev_dealt: MessageWriter<DamageDealtMessage>,
 ) {
     for ev in ev_damage.read() {
-        if let Ok((mut health, stats, active_buffs)) = q_health.get_mut(ev.target) {
-            // Damage = max(1, Final Atk - Final Def)
-            let base_damage = (ev.damage - stats.defense).max(1.0);
-
-            // Block chance: 5% per stack, blocks reduce damage by 50%
-            let block_mult = if let Some(mut buffs) = active_buffs {
-                let mut blocked = false;
-                for buff in &mut buffs.list {
-                    if let BuffEffect::Block(data) = buff {
-                        if data.current_stacks > 0 {
-                            let block_chance = data.current_stacks as f32 * 0.05;
-                            if rng.random::<f32>() < block_chance {
-                                blocked = true;
-                                data.current_stacks -= 1;
-                                data.regen_timer.reset();
-                                break;
-                            }
-                        }
-                    }
-                }
-                if blocked { 0.5 } else { 1.0 }
-            } else {
-                1.0
+        if let Ok((mut health, stats, active_effects)) = q_health.get_mut(ev.target) {
+            // Counter bonus, max(1, Final Atk - Final Def), resistances: see `DamageFormula`
+            let mut breakdown = formula.compute(
+                ev.damage,
+                &DamageContext {
+                    damage_type: ev.damage_type,
+                    attacker: ev
+                        .attacker
+                        .and_then(|attacker| q_attackers.get(attacker).ok()),
+                    target: stats,
+                },
+            );
+
+            // On-hit effects like Block may soften the blow
+            let block_mult = match active_effects {
+                Some(mut effects) => effects.roll_on_hit(&registry, &mut **rng),
+                None => 1.0,
             };
-
-            let actual_damage = base_damage * block_mult;
-            health.take_damage(actual_damage);
+            if block_mult != 1.0 {
+                breakdown.push("block", breakdown.total() * block_mult);
+            }
+
+            let health_left = health.get_current().max(0.0);
+            health.take_damage(breakdown.total());
+            ev_dealt.write(DamageDealtMessage {
+                attacker: ev.attacker,
+                target: ev.target,
+                amount: breakdown.total().clamp(0.0, health_left),
+            });
+            commands
+                .entity(ev.target)
+                .try_insert(LastHitTaken(breakdown));
             let hit_pos = q_transform
                 .get(ev.target)
                 .map(|t| t.translation().truncate())
@@ -208,3 +226,11 @@ fn take_damage_system(
         }
     }
 }
+
+fn heal_system(mut ev_heal: MessageReader<HealMessage>, mut q_health: Query<&mut Health>) {
+    for ev in ev_heal.read() {
+        if let Ok(mut health) = q_health.get_mut(ev.target) {
+            health.heal(ev.amount);
+        }
+    }
+}
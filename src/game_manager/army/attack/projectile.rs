@@ -0,0 +1,404 @@
+//! Anything fired at a target: arrows today, memories and new ranged units later.
+//!
+//! A [`Projectile`] flies to a landing point and only hurts whoever is there when it lands
+//! (or, for piercing shots, whoever it passes through), so units can dodge by moving.
+
+use crate::prelude::*;
+
+/// How close to a unit's collider a projectile has to land to hit it.
+const HIT_RADIUS: f32 = 12.0;
+
+/// Peak of an arrow's arc in pixels.
+const DEFAULT_ARC_HEIGHT: f32 = 80.0;
+/// Seconds an arrow is in the air.
+const DEFAULT_FLIGHT_DURATION: f32 = 0.6;
+/// Pixels per second of straight and homing shots without a speed in their spec.
+const DEFAULT_SPEED: f32 = 600.0;
+/// Radius of a splash without one in its spec.
+const DEFAULT_SPLASH_RADIUS: f32 = 96.0;
+/// Share of the damage a splash still deals at its rim.
+const SPLASH_EDGE_SHARE: f32 = 0.25;
+/// Units a piercing shot goes through without a count in its spec.
+const DEFAULT_PIERCE_COUNT: u32 = 2;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(
+        Update,
+        (projectile_movement_system, projectile_impact_system)
+            .chain()
+            .after(AttackSet::Attack)
+            .before(AttackSet::TakeDamage)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// How a projectile gets to where it lands.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ProjectileTravel {
+    /// Arcs over everything and lands after `duration` seconds.
+    Ballistic { max_height: f32, duration: f32 },
+    /// Flies in a straight line at `speed` pixels per second.
+    Straight { speed: f32 },
+    /// Chases its target at `speed`. If the target dies, lands where it was last seen.
+    Homing { speed: f32 },
+}
+
+impl ProjectileTravel {
+    /// Seconds the projectile takes to cover `distance`.
+    fn flight_time(self, distance: f32) -> f32 {
+        match self {
+            ProjectileTravel::Ballistic { duration, .. } => duration,
+            ProjectileTravel::Straight { speed } | ProjectileTravel::Homing { speed } => {
+                distance / speed.max(1.0)
+            }
+        }
+    }
+}
+
+/// Who a projectile hurts.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ProjectileImpact {
+    /// The enemy closest to the landing point, if any is there.
+    Single,
+    /// Every enemy within `radius` of the landing point. Damage falls off linearly
+    /// to `edge_share` of the full damage at the rim.
+    Splash { radius: f32, edge_share: f32 },
+    /// The first `count` enemies the projectile passes through on its way.
+    Pierce { count: u32 },
+}
+
+/// How a ranged unit's shots fly and hit, from the `projectile` column of `all.unit.csv`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ProjectileSpec {
+    pub travel: ProjectileTravel,
+    pub impact: ProjectileImpact,
+}
+
+/// An arrow: a ballistic arc that hits one unit.
+impl Default for ProjectileSpec {
+    fn default() -> Self {
+        Self {
+            travel: ProjectileTravel::Ballistic {
+                max_height: DEFAULT_ARC_HEIGHT,
+                duration: DEFAULT_FLIGHT_DURATION,
+            },
+            impact: ProjectileImpact::Single,
+        }
+    }
+}
+
+impl ProjectileSpec {
+    /// Names accepted by [`ProjectileSpec::from_spec`].
+    pub const NAMES: &[&str] = &[
+        "Ballistic",
+        "Straight",
+        "Homing",
+        "Single",
+        "Splash",
+        "Pierce",
+    ];
+
+    /// Parses a travel and an impact separated by `;`, each optionally followed by `:value`,
+    /// e.g. `Straight:600;Pierce:3` or `Splash:120`. A missing part stays as an arrow's.
+    /// Returns the first part it doesn't understand as the error.
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        for part in spec
+            .split(';')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let (name, value) = match part.split_once(':') {
+                Some((name, value)) => {
+                    let value: f32 = value.trim().parse().map_err(|_| part.to_string())?;
+                    (name.trim(), Some(value))
+                }
+                None => (part, None),
+            };
+            match name {
+                "Ballistic" => {
+                    parsed.travel = ProjectileTravel::Ballistic {
+                        max_height: DEFAULT_ARC_HEIGHT,
+                        duration: value.unwrap_or(DEFAULT_FLIGHT_DURATION),
+                    }
+                }
+                "Straight" => {
+                    parsed.travel = ProjectileTravel::Straight {
+                        speed: value.unwrap_or(DEFAULT_SPEED),
+                    }
+                }
+                "Homing" => {
+                    parsed.travel = ProjectileTravel::Homing {
+                        speed: value.unwrap_or(DEFAULT_SPEED),
+                    }
+                }
+                "Single" => parsed.impact = ProjectileImpact::Single,
+                "Splash" => {
+                    parsed.impact = ProjectileImpact::Splash {
+                        radius: value.unwrap_or(DEFAULT_SPLASH_RADIUS),
+                        edge_share: SPLASH_EDGE_SHARE,
+                    }
+                }
+                "Pierce" => {
+                    parsed.impact = ProjectileImpact::Pierce {
+                        count: value.map_or(DEFAULT_PIERCE_COUNT, |count| count as u32),
+                    }
+                }
+                _ => return Err(part.to_string()),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// A projectile in flight. The entity's Transform is its ground position; for ballistic
+/// shots the height of the arc goes on the [`Model`] child.
+#[derive(Component)]
+#[require(Transform)]
+pub struct Projectile {
+    pub travel: ProjectileTravel,
+    pub impact: ProjectileImpact,
+    pub damage: f32,
+    pub damage_type: DamageType,
+    /// The unit that fired it.
+    pub shooter: Entity,
+    /// The side that fired it; only the other side gets hit.
+    pub faction: Faction,
+    /// Followed by homing projectiles while it lives.
+    pub target: Option<Entity>,
+    start: Vec2,
+    end: Vec2,
+    elapsed: f32,
+    /// Units a piercing projectile already went through.
+    hit: Vec<Entity>,
+    landed: bool,
+}
+
+impl Projectile {
+    /// A projectile that still has to be [aimed](Self::aimed_at).
+    pub fn new(
+        spec: ProjectileSpec,
+        damage: f32,
+        damage_type: DamageType,
+        shooter: Entity,
+        faction: Faction,
+    ) -> Self {
+        Self {
+            travel: spec.travel,
+            impact: spec.impact,
+            damage,
+            damage_type,
+            shooter,
+            faction,
+            target: None,
+            start: Vec2::ZERO,
+            end: Vec2::ZERO,
+            elapsed: 0.0,
+            hit: Vec::new(),
+            landed: false,
+        }
+    }
+
+    /// Fires from `start` at `target`, standing at `target_pos` and moving at
+    /// `target_velocity`. Unguided shots lead the target; homing ones follow it.
+    pub fn aimed_at(
+        mut self,
+        start: Vec2,
+        target: Entity,
+        target_pos: Vec2,
+        target_velocity: Vec2,
+    ) -> Self {
+        let lead = match self.travel {
+            ProjectileTravel::Homing { .. } => Vec2::ZERO,
+            travel => target_velocity * travel.flight_time(start.distance(target_pos)),
+        };
+        self.target = Some(target);
+        self.start = start;
+        self.end = target_pos + lead;
+        self
+    }
+
+    pub fn landing_point(&self) -> Vec2 {
+        self.end
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Moves projectiles along their path and lifts ballistic ones onto their arc.
+fn projectile_movement_system(
+    time: Res<Time>,
+    mut q_projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
+    q_targets: Query<&GlobalTransform, With<Unit>>,
+    q_model_belongs: Query<(Entity, &BelongTo), With<Model>>,
+    mut q_model_transform: Query<&mut Transform, (With<Model>, Without<Projectile>)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut projectile, mut transform) in &mut q_projectiles {
+        let pos = transform.translation.truncate();
+        projectile.elapsed += delta;
+
+        let (next_pos, height, tilt) = match projectile.travel {
+            ProjectileTravel::Ballistic {
+                max_height,
+                duration,
+            } => {
+                let t = (projectile.elapsed / duration).min(1.0);
+                // h = 4 * max_height * t * (1 - t) peaks at t = 0.5
+                let height = 4.0 * max_height * t * (1.0 - t);
+                // Tilt follows the slope of the arc: dh/dt = 4 * max_height * (1 - 2t)
+                let slope = 4.0 * max_height * (1.0 - 2.0 * t);
+                let horizontal_dist = projectile.start.distance(projectile.end);
+                let tilt = if horizontal_dist > 0.0 {
+                    (slope / horizontal_dist).atan()
+                } else {
+                    0.0
+                };
+                projectile.landed = t >= 1.0;
+                (projectile.start.lerp(projectile.end, t), height, tilt)
+            }
+            ProjectileTravel::Straight { speed } | ProjectileTravel::Homing { speed } => {
+                let followed = match projectile.travel {
+                    ProjectileTravel::Homing { .. } => projectile.target,
+                    _ => None,
+                };
+                if let Some(target_transform) =
+                    followed.and_then(|target| q_targets.get(target).ok())
+                {
+                    projectile.end = target_transform.translation().truncate();
+                }
+                let to_end = projectile.end - pos;
+                let step = speed * delta;
+                projectile.landed = to_end.length() <= step;
+                let next_pos = if projectile.landed {
+                    projectile.end
+                } else {
+                    pos + to_end.normalize_or_zero() * step
+                };
+                (next_pos, 0.0, 0.0)
+            }
+        };
+
+        transform.translation.x = next_pos.x;
+        transform.translation.y = next_pos.y;
+
+        let direction = (projectile.end - projectile.start).normalize_or_zero();
+        let heading = if matches!(projectile.travel, ProjectileTravel::Homing { .. }) {
+            (next_pos - pos).try_normalize().unwrap_or(direction)
+        } else {
+            direction
+        };
+        transform.rotation = Quat::from_rotation_z(heading.y.atan2(heading.x) + tilt);
+
+        for (model_entity, belong_to) in &q_model_belongs {
+            if belong_to.0 == entity {
+                if let Ok(mut model_transform) = q_model_transform.get_mut(model_entity) {
+                    // Update Y for visual height (Z stays unchanged for layering)
+                    model_transform.translation.y = height;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Hits whoever is in the way: piercing shots along their path, the rest where they land.
+fn projectile_impact_system(
+    grid: Res<UnitSpatialGrid>,
+    mut q_projectiles: Query<(Entity, &mut Projectile, &Transform)>,
+    mut commands: Commands,
+) {
+    for (entity, mut projectile, transform) in &mut q_projectiles {
+        let pos = transform.translation.truncate();
+
+        match projectile.impact {
+            ProjectileImpact::Pierce { count } => {
+                let mut passed = enemies_near(&grid, &projectile, pos, HIT_RADIUS);
+                passed.retain(|(unit, _)| !projectile.hit.contains(unit));
+                for (unit, _) in passed {
+                    if projectile.hit.len() as u32 >= count {
+                        break;
+                    }
+                    commands.trigger(AttackEvent::new(
+                        projectile.shooter,
+                        unit,
+                        projectile.damage,
+                        projectile.damage_type,
+                    ));
+                    projectile.hit.push(unit);
+                }
+                if projectile.landed || projectile.hit.len() as u32 >= count {
+                    commands.entity(entity).despawn();
+                }
+                continue;
+            }
+            _ if !projectile.landed => continue,
+            ProjectileImpact::Single => {
+                if let Some(&(unit, _)) = enemies_near(&grid, &projectile, pos, HIT_RADIUS).first()
+                {
+                    commands.trigger(AttackEvent::new(
+                        projectile.shooter,
+                        unit,
+                        projectile.damage,
+                        projectile.damage_type,
+                    ));
+                }
+            }
+            ProjectileImpact::Splash { radius, edge_share } => {
+                for (unit, dist) in enemies_near(&grid, &projectile, pos, radius) {
+                    let falloff = (dist / radius).min(1.0);
+                    let share = 1.0 - (1.0 - edge_share) * falloff;
+                    commands.trigger(AttackEvent::new(
+                        projectile.shooter,
+                        unit,
+                        projectile.damage * share,
+                        projectile.damage_type,
+                    ));
+                }
+            }
+        }
+
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Enemies of the projectile's side within `radius` of `pos`, nearest first.
+fn enemies_near(
+    grid: &UnitSpatialGrid,
+    projectile: &Projectile,
+    pos: Vec2,
+    radius: f32,
+) -> Vec<(Entity, f32)> {
+    let mut enemies = Vec::new();
+    grid.for_each_within(pos, radius, |unit, dist| {
+        if unit.faction != projectile.faction {
+            enemies.push((unit.entity, dist));
+        }
+    });
+    enemies.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    enemies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs_parse_travel_and_impact() {
+        assert_eq!(ProjectileSpec::from_spec(""), Ok(ProjectileSpec::default()));
+        assert_eq!(
+            ProjectileSpec::from_spec("Straight:500; Pierce:3"),
+            Ok(ProjectileSpec {
+                travel: ProjectileTravel::Straight { speed: 500.0 },
+                impact: ProjectileImpact::Pierce { count: 3 },
+            })
+        );
+        assert_eq!(ProjectileSpec::from_spec("Lob"), Err("Lob".to_string()));
+    }
+}
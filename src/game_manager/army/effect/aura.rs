@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// Seconds between aura pulses.
+const AURA_INTERVAL: f32 = 1.0;
+
+/// How far an aura reaches from its carrier.
+const AURA_RADIUS: f32 = 160.0;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.init_resource::<AuraPulse>();
+    app.add_systems(OnEnter(GameState::Battle), reset_aura_pulse);
+    app.add_systems(
+        Update,
+        spread_auras
+            .after(MovementSet::SpatialGridUpdate)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+/// Seconds since the last aura pulse of this battle.
+#[derive(Resource, Default)]
+struct AuraPulse {
+    elapsed: f32,
+}
+
+fn reset_aura_pulse(mut pulse: ResMut<AuraPulse>) {
+    *pulse = AuraPulse::default();
+}
+
+/// Every [`AURA_INTERVAL`], each `aura` effect goes out to the carrier's allies nearby.
+fn spread_auras(
+    time: Res<Time>,
+    mut pulse: ResMut<AuraPulse>,
+    registry: Res<EffectRegistry>,
+    grid: Res<UnitSpatialGrid>,
+    mut q_units: Query<(&mut ActiveEffects, &GlobalTransform, &Faction)>,
+) {
+    pulse.elapsed += time.delta_secs();
+    if pulse.elapsed < AURA_INTERVAL {
+        return;
+    }
+    pulse.elapsed -= AURA_INTERVAL;
+
+    let mut applied = Vec::new();
+    for (effects, transform, faction) in &q_units {
+        for effect in effects.list.iter().filter(|effect| effect.stacks > 0) {
+            let Some(aura) = registry
+                .get(&effect.id)
+                .and_then(|definition| definition.aura.as_ref())
+            else {
+                continue;
+            };
+            let Some(definition) = registry.get(&aura.id) else {
+                continue;
+            };
+            let stacks = aura.stacks.unwrap_or(effect.stacks);
+            let pos = transform.translation().truncate();
+            grid.for_each_within(pos, AURA_RADIUS, |neighbor, _| {
+                if neighbor.faction == *faction {
+                    applied.push((neighbor.entity, definition, stacks));
+                }
+            });
+        }
+    }
+
+    for (ally, definition, stacks) in applied {
+        if let Ok((mut effects, _, _)) = q_units.get_mut(ally) {
+            effects.apply(definition, stacks);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_observer(on_attack_apply_effects);
+    app.add_systems(
+        Update,
+        (
+            prevent_attack_when_stunned.before(AttackSet::Attack),
+            apply_effect_modifiers,
+        ),
+    );
+}
+
+/// When a unit attacks, each of its `on_attack` effects may put an effect on the target.
+fn on_attack_apply_effects(
+    trigger: On<AttackEvent>,
+    registry: Res<EffectRegistry>,
+    mut rng: ResMut<BattleRng>,
+    mut q_effects: Query<&mut ActiveEffects>,
+) {
+    let Ok(attacker_effects) = q_effects.get(trigger._from) else {
+        return;
+    };
+    let applied = attacker_effects.roll_on_attack(&registry, &mut **rng);
+
+    let Ok(mut target_effects) = q_effects.get_mut(trigger.to) else {
+        return;
+    };
+    for (definition, stacks) in applied {
+        target_effects.apply(definition, stacks);
+    }
+}
+
+/// Prevents stunned units from attacking by forcing them out of Attacking state.
+fn prevent_attack_when_stunned(
+    registry: Res<EffectRegistry>,
+    mut query: Query<(&ActiveEffects, &mut UnitAction)>,
+) {
+    for (effects, mut state) in &mut query {
+        if *state == UnitAction::Attacking && effects.stuns(&registry) {
+            *state = UnitAction::Idle;
+        }
+    }
+}
+
+/// Keeps each unit's effect stat changes in step with its stacks.
+fn apply_effect_modifiers(
+    registry: Res<EffectRegistry>,
+    mut query: Query<(&ActiveEffects, &mut StatModifiers), Changed<ActiveEffects>>,
+) {
+    for (effects, mut modifiers) in &mut query {
+        let wanted: Vec<StatModifier> = effects
+            .list
+            .iter()
+            .filter(|effect| effect.stacks > 0)
+            .filter_map(|effect| {
+                let modifier = registry.get(&effect.id)?.modifier?;
+                Some(StatModifier {
+                    bucket: modifier.bucket,
+                    ..StatModifier::flat(
+                        modifier.stat,
+                        ModifierSource::Effect(effect.id.clone()),
+                        modifier.per_stack * effect.stacks as f32,
+                    )
+                })
+            })
+            .collect();
+
+        let is_effect = |source: &ModifierSource| matches!(source, ModifierSource::Effect(_));
+        let current = modifiers
+            .iter()
+            .filter(|modifier| is_effect(&modifier.source))
+            .cloned();
+        if current.eq(wanted.iter().cloned()) {
+            continue;
+        }
+        modifiers.replace_sources(is_effect, wanted);
+    }
+}
@@ -1,12 +1,210 @@
+//! Status effects: buffs a unit is born with and statuses it picks up in battle.
+//!
+//! What an effect does lives in `all.effect.csv` ([`EffectRegistry`]); units only carry
+//! the name and stack count of each effect they have.
 
-mod buff;
-pub(crate) use buff::*;
+use rand::Rng;
+use smol_str::SmolStr;
 
-mod debuff;
-pub(crate) use debuff::*;
+use crate::prelude::*;
+
+mod aura;
+mod hooks;
+mod tick;
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
-    buff::plugin(app);
-    debuff::plugin(app);
+    app.init_resource::<EffectRegistry>();
+    aura::plugin(app);
+    hooks::plugin(app);
+    tick::plugin(app);
+}
+
+/// Name of an effect: the `id` of its row in `all.effect.csv`.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[serde(transparent)]
+#[reflect(opaque)]
+pub struct EffectId(SmolStr);
+
+impl EffectId {
+    pub fn new(id: &str) -> Self {
+        Self(SmolStr::new(id))
+    }
+}
+
+impl AsRef<str> for EffectId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for EffectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An effect and how many stacks of it to give.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct EffectSpec {
+    pub id: EffectId,
+    pub stacks: Option<u32>,
+}
+
+impl EffectSpec {
+    /// Parses a name, optionally followed by `:stacks`, e.g. `Block` or `AttackSpeed:1`.
+    pub fn from_spec(spec: &str) -> Option<Self> {
+        let (name, stacks) = match spec.split_once(':') {
+            Some((name, stacks)) => (name.trim(), Some(stacks.trim().parse().ok()?)),
+            None => (spec.trim(), None),
+        };
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            id: EffectId::new(name),
+            stacks,
+        })
+    }
+}
+
+/// One effect on a unit.
+#[derive(Clone, Debug, Reflect)]
+pub struct ActiveEffect {
+    pub id: EffectId,
+    pub stacks: u32,
+    /// Seconds left, or `None` for effects that last the whole battle.
+    pub remaining: Option<f32>,
+    regen_elapsed: f32,
+    tick_elapsed: f32,
 }
 
+impl ActiveEffect {
+    fn new(id: EffectId, stacks: u32, remaining: Option<f32>) -> Self {
+        Self {
+            id,
+            stacks,
+            remaining,
+            regen_elapsed: 0.0,
+            tick_elapsed: 0.0,
+        }
+    }
+}
+
+/// Every effect on a unit, in the order it got them.
+#[derive(Component, Default, Reflect)]
+pub struct ActiveEffects {
+    pub list: Vec<ActiveEffect>,
+}
+
+impl ActiveEffects {
+    /// The effects a unit is born with. They never run out.
+    pub fn innate(specs: &[EffectSpec]) -> Self {
+        Self {
+            list: specs
+                .iter()
+                .map(|spec| ActiveEffect::new(spec.id.clone(), spec.stacks.unwrap_or(0), None))
+                .collect(),
+        }
+    }
+
+    /// Gives the unit `stacks` of `definition`, combined with any it already has
+    /// by the effect's [`EffectStacking`], and restarts its duration.
+    pub fn apply(&mut self, definition: &EffectRow, stacks: u32) {
+        let id = EffectId::new(&definition.id);
+        let Some(effect) = self.list.iter_mut().find(|effect| effect.id == id) else {
+            self.list.push(ActiveEffect::new(
+                id,
+                stacks.min(definition.max_stacks),
+                definition.duration,
+            ));
+            return;
+        };
+
+        effect.stacks = match definition.stacking {
+            EffectStacking::Add => effect.stacks + stacks,
+            EffectStacking::Refresh => effect.stacks.max(stacks),
+        }
+        .min(definition.max_stacks);
+        effect.tick_elapsed = 0.0;
+        if effect.remaining.is_some() {
+            effect.remaining = definition.duration;
+        }
+    }
+
+    /// Whether any effect keeps the unit from attacking.
+    pub fn stuns(&self, registry: &EffectRegistry) -> bool {
+        self.list
+            .iter()
+            .filter_map(|effect| registry.get(&effect.id))
+            .any(|definition| definition.stuns)
+    }
+
+    /// Rolls every `on_attack` effect; returns the effects that trigger and how many
+    /// stacks of each to put on the target.
+    pub fn roll_on_attack<'a>(
+        &self,
+        registry: &'a EffectRegistry,
+        rng: &mut impl Rng,
+    ) -> Vec<(&'a EffectRow, u32)> {
+        let mut applied = Vec::new();
+        for effect in &self.list {
+            if effect.stacks == 0 {
+                continue;
+            }
+            let Some(definition) = registry.get(&effect.id) else {
+                continue;
+            };
+            let Some(on_attack) = &definition.on_attack else {
+                continue;
+            };
+            if !rolls(definition, effect.stacks, rng) {
+                continue;
+            }
+            if let Some(target_definition) = registry.get(&on_attack.id) {
+                applied.push((target_definition, on_attack.stacks.unwrap_or(effect.stacks)));
+            }
+        }
+        applied
+    }
+
+    /// Removes the oldest debuff, if the unit has any. Returns whether it had one.
+    pub fn cleanse(&mut self, registry: &EffectRegistry) -> bool {
+        let debuff = self.list.iter().position(|effect| {
+            registry
+                .get(&effect.id)
+                .is_some_and(|definition| definition.debuff)
+        });
+        if let Some(index) = debuff {
+            self.list.remove(index);
+        }
+        debuff.is_some()
+    }
+
+    /// Rolls every `on_hit_damage` effect in turn; the first that triggers uses up a
+    /// stack and returns its damage multiplier.
+    pub fn roll_on_hit(&mut self, registry: &EffectRegistry, rng: &mut impl Rng) -> f32 {
+        for effect in &mut self.list {
+            let Some(definition) = registry.get(&effect.id) else {
+                continue;
+            };
+            let Some(multiplier) = definition.on_hit_damage else {
+                continue;
+            };
+            if effect.stacks == 0 || !rolls(definition, effect.stacks, rng) {
+                continue;
+            }
+            effect.stacks -= 1;
+            effect.regen_elapsed = 0.0;
+            return multiplier;
+        }
+        1.0
+    }
+}
+
+/// Whether an effect with `stacks` stacks triggers, by its `chance` per stack.
+fn rolls(definition: &EffectRow, stacks: u32, rng: &mut impl Rng) -> bool {
+    match definition.chance {
+        Some(chance) => rng.random::<f32>() < chance * stacks as f32,
+        None => true,
+    }
+}
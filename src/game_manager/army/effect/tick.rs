@@ -0,0 +1,176 @@
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(Update, tick_effects);
+}
+
+impl ActiveEffects {
+    /// Advances every effect by `delta` seconds: regains stacks, deals periodic damage,
+    /// loses decaying stacks and drops effects that ran out.
+    ///
+    /// Returns the damage dealt to the carrier (negative when it heals) and whether any
+    /// stack count changed.
+    fn tick(&mut self, delta: f32, registry: &EffectRegistry) -> (f32, bool) {
+        let mut damage = 0.0;
+        let mut changed = false;
+        let before = self.list.len();
+
+        self.list.retain_mut(|effect| {
+            let Some(definition) = registry.get(&effect.id) else {
+                return true;
+            };
+
+            if let Some(regen) = definition.regen {
+                effect.regen_elapsed += delta;
+                if effect.regen_elapsed >= regen {
+                    effect.regen_elapsed = 0.0;
+                    if effect.stacks < definition.max_stacks {
+                        effect.stacks += 1;
+                        changed = true;
+                    }
+                }
+            }
+
+            let mut decayed = false;
+            if let Some(tick) = definition.tick.filter(|_| effect.stacks > 0) {
+                effect.tick_elapsed += delta;
+                if effect.tick_elapsed >= tick {
+                    effect.tick_elapsed -= tick;
+                    damage += definition.tick_damage.unwrap_or(0.0) * effect.stacks as f32;
+                    if let Some(decay) = definition.tick_decay.filter(|decay| *decay > 0) {
+                        effect.stacks = effect.stacks.saturating_sub(decay);
+                        decayed = effect.stacks == 0;
+                        changed = true;
+                    }
+                }
+            }
+
+            let expired = effect.remaining.as_mut().is_some_and(|remaining| {
+                *remaining -= delta;
+                *remaining <= 0.0
+            });
+            !decayed && !expired
+        });
+
+        (damage, changed || self.list.len() != before)
+    }
+}
+
+/// Ticks every unit's effects and applies their periodic damage and healing.
+fn tick_effects(
+    time: Res<Time>,
+    registry: Res<EffectRegistry>,
+    mut query: Query<(Entity, &mut ActiveEffects, &mut Health)>,
+    mut ev_heal: MessageWriter<HealMessage>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut effects, mut health) in &mut query {
+        // Timers move every frame; only stack changes count as a change to the effects
+        let (damage, changed) = effects.bypass_change_detection().tick(delta, &registry);
+        if changed {
+            effects.set_changed();
+        }
+        if damage > 0.0 {
+            health.take_damage(damage);
+        } else if damage < 0.0 {
+            ev_heal.write(HealMessage {
+                target: entity,
+                amount: -damage,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POISON: &str = "\
+id,max_stacks,stacking,regen,duration,tick,tick_damage,tick_decay,chance,on_attack,on_hit_damage,modifier,stuns,debuff,aura,text
+Poisoned,5,Add,,,1,5,1,,,,,,true,,Poisoned x{stacks}
+";
+
+    /// Ticks the poison for `seconds` and applies its damage, like `tick_effects` does.
+    fn manual_poison_tick(world: &mut World, entity: Entity, seconds: f32) {
+        let registry = EffectRegistry::from_csv_str(POISON).unwrap();
+        let mut entity = world.entity_mut(entity);
+        let (damage, _) = entity
+            .get_mut::<ActiveEffects>()
+            .unwrap()
+            .tick(seconds, &registry);
+        entity.get_mut::<Health>().unwrap().take_damage(damage);
+    }
+
+    fn poisoned(world: &mut World, stacks: u32) -> Entity {
+        let registry = EffectRegistry::from_csv_str(POISON).unwrap();
+        let mut effects = ActiveEffects::default();
+        effects.apply(&registry.effects["Poisoned"], stacks);
+        world.spawn((Health::new_full(100.0), effects)).id()
+    }
+
+    /// Stacks of poison left; 0 once it's gone.
+    fn poison_stacks(world: &World, entity: Entity) -> u32 {
+        let effects = world.entity(entity).get::<ActiveEffects>().unwrap();
+        effects.list.iter().map(|effect| effect.stacks).sum()
+    }
+
+    fn health(world: &World, entity: Entity) -> f32 {
+        world.entity(entity).get::<Health>().unwrap().get_current()
+    }
+
+    #[test]
+    fn test_poison_applies_debuff() {
+        let mut world = World::new();
+        let entity = poisoned(&mut world, 3);
+
+        assert_eq!(poison_stacks(&world, entity), 3);
+    }
+
+    #[test]
+    fn test_poison_is_capped_at_max_stacks() {
+        let mut world = World::new();
+        let entity = poisoned(&mut world, 9);
+
+        assert_eq!(poison_stacks(&world, entity), 5);
+    }
+
+    #[test]
+    fn test_poison_reduces_health_and_stacks_on_tick() {
+        let mut world = World::new();
+        let entity = poisoned(&mut world, 3);
+
+        // 3 stacks, 5 damage per tick: 100 - 15 = 85
+        manual_poison_tick(&mut world, entity, 1.0);
+
+        assert_eq!(health(&world, entity), 85.0);
+        assert_eq!(poison_stacks(&world, entity), 2);
+    }
+
+    #[test]
+    fn test_poison_stops_at_zero_stacks() {
+        let mut world = World::new();
+        let entity = poisoned(&mut world, 3);
+
+        // 100 - 15 - 10 - 5 = 70
+        for _ in 0..3 {
+            manual_poison_tick(&mut world, entity, 1.0);
+        }
+        assert_eq!(health(&world, entity), 70.0);
+        assert_eq!(poison_stacks(&world, entity), 0);
+
+        // The poison is gone, so another tick does nothing
+        manual_poison_tick(&mut world, entity, 1.0);
+        assert_eq!(health(&world, entity), 70.0);
+    }
+
+    #[test]
+    fn test_no_damage_before_timer_expires() {
+        let mut world = World::new();
+        let entity = poisoned(&mut world, 3);
+
+        manual_poison_tick(&mut world, entity, 0.5);
+
+        assert_eq!(health(&world, entity), 100.0);
+        assert_eq!(poison_stacks(&world, entity), 3);
+    }
+}
@@ -16,22 +16,37 @@ pub(crate) use enemy::*;
 mod player;
 pub(crate) use player::*;
 
-// mod morale;
-// pub(crate) use morale::*;
+mod morale;
+pub(crate) use morale::*;
 
 mod color;
 
 mod effect;
 pub(crate) use effect::*;
 
+mod modifier;
+pub(crate) use modifier::*;
+
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     units::plugin(app);
     squad::plugin(app);
     movement::plugin(app);
     attack::plugin(app);
     enemy::plugin(app);
-    // morale::plugin(app);
+    morale::plugin(app);
     player::plugin(app);
     color::plugin(app);
     effect::plugin(app);
+    modifier::plugin(app);
+}
+
+/// Combat systems only, for running battles without a window, renderer or LDtk.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    units::headless_plugin(app);
+    squad::headless_plugin(app);
+    movement::headless_plugin(app);
+    attack::headless_plugin(app);
+    morale::plugin(app);
+    effect::plugin(app);
+    modifier::plugin(app);
 }
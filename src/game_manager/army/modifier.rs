@@ -0,0 +1,369 @@
+use std::{fmt, time::Duration};
+
+use crate::{game_manager::BattleSystems, prelude::*};
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(
+        Update,
+        (
+            tick_stat_modifiers.run_if(in_state(GameState::Battle)),
+            apply_stat_modifiers,
+            sync_attack_timer,
+        )
+            .chain()
+            .after(BattleSystems::UpdateUnitValue),
+    );
+}
+
+// ============================================================================
+// Modifiers
+// ============================================================================
+
+/// A [`CombatAttributes`] value a modifier can change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Stat {
+    Speed,
+    AttackRange,
+    Damage,
+    /// Seconds between attacks, so a positive modifier makes the unit attack slower.
+    AttackSpeed,
+    Defense,
+}
+
+impl Stat {
+    /// Short name as shown in the unit tooltip.
+    pub fn label(self) -> &'static str {
+        match self {
+            Stat::Speed => "SPD",
+            Stat::AttackRange => "RNG",
+            Stat::Damage => "ATK",
+            Stat::AttackSpeed => "ATK INT",
+            Stat::Defense => "DEF",
+        }
+    }
+
+    /// Every stat, in tooltip order.
+    pub const ALL: [Stat; 5] = [
+        Stat::Damage,
+        Stat::Defense,
+        Stat::Speed,
+        Stat::AttackRange,
+        Stat::AttackSpeed,
+    ];
+
+    /// The stat named like its variant, e.g. `AttackSpeed`.
+    pub fn from_name(name: &str) -> Option<Stat> {
+        Stat::ALL
+            .into_iter()
+            .find(|stat| format!("{stat:?}") == name)
+    }
+
+    pub fn value(self, stats: &CombatAttributes) -> f32 {
+        match self {
+            Stat::Speed => stats.speed,
+            Stat::AttackRange => stats.attack_range,
+            Stat::Damage => stats.damage,
+            Stat::AttackSpeed => stats.attack_speed,
+            Stat::Defense => stats.defense,
+        }
+    }
+
+    fn value_mut(self, stats: &mut CombatAttributes) -> &mut f32 {
+        match self {
+            Stat::Speed => &mut stats.speed,
+            Stat::AttackRange => &mut stats.attack_range,
+            Stat::Damage => &mut stats.damage,
+            Stat::AttackSpeed => &mut stats.attack_speed,
+            Stat::Defense => &mut stats.defense,
+        }
+    }
+
+    /// Lowest value modifiers can push the stat to.
+    fn floor(self) -> f32 {
+        match self {
+            Stat::AttackSpeed => 0.05,
+            Stat::Defense => f32::MIN,
+            _ => 0.0,
+        }
+    }
+}
+
+/// What put a modifier on a unit. Each source replaces its own modifiers as a whole.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub enum ModifierSource {
+    /// The ground the unit stands on.
+    Terrain(Terrain),
+    /// A status effect, scaled by its stacks.
+    Effect(EffectId),
+    /// The damage bonus of a BigEye memory watching the unit's squad.
+    BigEye,
+    /// The bonuses of the squad's veterancy rank.
+    Veterancy,
+}
+
+impl fmt::Display for ModifierSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModifierSource::Terrain(terrain) => write!(f, "{terrain:?}"),
+            ModifierSource::Effect(id) => write!(f, "{id}"),
+            ModifierSource::BigEye => write!(f, "BigEye"),
+            ModifierSource::Veterancy => write!(f, "Veterancy"),
+        }
+    }
+}
+
+/// Which part of the calculation a modifier joins. The base bucket is the unit's
+/// [`BaseCombatAttributes`]; the final value is `(base + flat) * (1 + percent)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ModifierBucket {
+    Flat,
+    /// `0.2` is +20%.
+    Percent,
+}
+
+/// What happens when a source puts a modifier on a stat and bucket it already modifies.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum Stacking {
+    /// The new modifier takes the old one's place.
+    #[default]
+    Replace,
+    /// Only the larger of the two is kept. An equal one refreshes the duration.
+    Strongest,
+}
+
+/// Changes one stat through one bucket, for as long as its source wants.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub source: ModifierSource,
+    pub bucket: ModifierBucket,
+    pub amount: f32,
+    /// Seconds of battle left before it wears off, or `None` until its source removes it.
+    pub remaining: Option<f32>,
+    pub stacking: Stacking,
+}
+
+impl StatModifier {
+    pub fn flat(stat: Stat, source: ModifierSource, amount: f32) -> Self {
+        Self {
+            stat,
+            source,
+            bucket: ModifierBucket::Flat,
+            amount,
+            remaining: None,
+            stacking: Stacking::default(),
+        }
+    }
+
+    pub fn percent(stat: Stat, source: ModifierSource, share: f32) -> Self {
+        Self {
+            bucket: ModifierBucket::Percent,
+            ..Self::flat(stat, source, share)
+        }
+    }
+
+    /// Wears off after `seconds` of battle.
+    pub fn lasting(mut self, seconds: f32) -> Self {
+        self.remaining = Some(seconds);
+        self
+    }
+
+    pub fn stacking(mut self, stacking: Stacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    fn same_slot(&self, other: &StatModifier) -> bool {
+        self.source == other.source && self.stat == other.stat && self.bucket == other.bucket
+    }
+}
+
+impl fmt::Display for StatModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bucket {
+            ModifierBucket::Flat => write!(f, "{} {:+.0}", self.source, self.amount)?,
+            ModifierBucket::Percent => write!(f, "{} {:+.0}%", self.source, self.amount * 100.0)?,
+        }
+        if let Some(remaining) = self.remaining {
+            write!(f, " ({remaining:.0}s)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Everything currently changing a unit's stats on top of its balance sheet.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+pub struct StatModifiers {
+    list: Vec<StatModifier>,
+}
+
+impl StatModifiers {
+    pub fn iter(&self) -> impl Iterator<Item = &StatModifier> {
+        self.list.iter()
+    }
+
+    /// Adds `modifier`, following its [`Stacking`] rule if its source already
+    /// modifies the same stat and bucket.
+    pub fn add(&mut self, modifier: StatModifier) {
+        let Some(existing) = self
+            .list
+            .iter_mut()
+            .find(|other| other.same_slot(&modifier))
+        else {
+            self.list.push(modifier);
+            return;
+        };
+        match modifier.stacking {
+            Stacking::Replace => *existing = modifier,
+            Stacking::Strongest if modifier.amount.abs() >= existing.amount.abs() => {
+                *existing = modifier
+            }
+            Stacking::Strongest => {}
+        }
+    }
+
+    /// Swaps every modifier of `source` for `modifiers`.
+    pub fn replace_source(
+        &mut self,
+        source: ModifierSource,
+        modifiers: impl IntoIterator<Item = StatModifier>,
+    ) {
+        self.replace_sources(|other| *other == source, modifiers);
+    }
+
+    /// Swaps every modifier whose source `is_replaced` for `modifiers`.
+    pub fn replace_sources(
+        &mut self,
+        is_replaced: impl Fn(&ModifierSource) -> bool,
+        modifiers: impl IntoIterator<Item = StatModifier>,
+    ) {
+        self.list.retain(|modifier| !is_replaced(&modifier.source));
+        for modifier in modifiers {
+            self.add(modifier);
+        }
+    }
+
+    /// Counts down timed modifiers and drops the ones that wore off.
+    /// Returns whether any did.
+    fn tick(&mut self, delta: f32) -> bool {
+        let before = self.list.len();
+        for remaining in self
+            .list
+            .iter_mut()
+            .filter_map(|modifier| modifier.remaining.as_mut())
+        {
+            *remaining -= delta;
+        }
+        self.list
+            .retain(|modifier| modifier.remaining.is_none_or(|remaining| remaining > 0.0));
+        self.list.len() != before
+    }
+
+    /// `base` with every modifier applied: flat amounts first, then percentages,
+    /// so a +20% bonus also scales a flat one.
+    pub fn apply(&self, base: &CombatAttributes) -> CombatAttributes {
+        let mut stats = base.clone();
+        for stat in Stat::ALL {
+            let (flat, percent) = self
+                .list
+                .iter()
+                .filter(|modifier| modifier.stat == stat)
+                .fold((0.0, 0.0), |(flat, percent), modifier| {
+                    match modifier.bucket {
+                        ModifierBucket::Flat => (flat + modifier.amount, percent),
+                        ModifierBucket::Percent => (flat, percent + modifier.amount),
+                    }
+                });
+            let value = stat.value_mut(&mut stats);
+            *value = ((*value + flat) * (1.0 + percent)).max(stat.floor());
+        }
+        stats
+    }
+}
+
+/// The stats a unit's balance sheet gives it, before any [`StatModifiers`].
+/// [`CombatAttributes`] holds the result of applying the modifiers to these.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct BaseCombatAttributes(pub CombatAttributes);
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Timed modifiers only wear off during battle.
+fn tick_stat_modifiers(time: Res<Time>, mut q_modifiers: Query<&mut StatModifiers>) {
+    let delta = time.delta_secs();
+    for mut modifiers in &mut q_modifiers {
+        // Only count as a change when something wore off, so stats aren't recomputed every frame
+        if modifiers.bypass_change_detection().tick(delta) {
+            modifiers.set_changed();
+        }
+    }
+}
+
+fn apply_stat_modifiers(
+    mut q_units: Query<
+        (&BaseCombatAttributes, &StatModifiers, &mut CombatAttributes),
+        Or<(Changed<BaseCombatAttributes>, Changed<StatModifiers>)>,
+    >,
+) {
+    for (base, modifiers, mut stats) in &mut q_units {
+        *stats = modifiers.apply(&base.0);
+    }
+}
+
+/// Attacks come as often as the final attack interval says.
+fn sync_attack_timer(
+    mut q_units: Query<(&CombatAttributes, &mut AttackTimer), Changed<CombatAttributes>>,
+) {
+    for (stats, mut attack_timer) in &mut q_units {
+        let duration = Duration::from_secs_f32(stats.attack_speed);
+        if attack_timer.0.duration() != duration {
+            attack_timer.0.set_duration(duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_stack_on_the_base_without_changing_it() {
+        let base = CombatAttributes::melee(UnitKind::new("Shield"));
+        let source = ModifierSource::Terrain(Terrain::HighGround);
+        let mut modifiers = StatModifiers::default();
+        modifiers.replace_source(
+            source.clone(),
+            [
+                StatModifier::flat(Stat::Defense, source.clone(), 2.0),
+                StatModifier::percent(Stat::AttackRange, source.clone(), 0.5),
+            ],
+        );
+
+        let stats = modifiers.apply(&base);
+        assert_eq!(stats.defense, base.defense + 2.0);
+        assert_eq!(stats.attack_range, base.attack_range * 1.5);
+
+        modifiers.replace_source(source, []);
+        assert_eq!(modifiers.apply(&base).attack_range, base.attack_range);
+    }
+
+    #[test]
+    fn strongest_timed_modifier_wins_and_wears_off() {
+        let base = CombatAttributes::melee(UnitKind::new("Shield"));
+        let bonus = |share: f32, seconds: f32| {
+            StatModifier::percent(Stat::Damage, ModifierSource::BigEye, share)
+                .lasting(seconds)
+                .stacking(Stacking::Strongest)
+        };
+        let mut modifiers = StatModifiers::default();
+        modifiers.add(bonus(0.5, 3.0));
+        modifiers.add(bonus(0.2, 10.0));
+        assert_eq!(modifiers.apply(&base).damage, base.damage * 1.5);
+
+        assert!(!modifiers.tick(2.0));
+        assert!(modifiers.tick(2.0));
+        assert_eq!(modifiers.apply(&base).damage, base.damage);
+    }
+}
@@ -3,6 +3,7 @@ use crate::{game_manager::BattleSystems, prelude::*};
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.init_resource::<CombatFlux>();
     app.add_message::<CombatMessage>();
+    app.add_systems(OnEnter(GameState::Battle), reset_combat_flux);
     app.add_systems(
         Update,
         calculate_combat_flux
@@ -59,11 +60,15 @@ impl CombatFlux {
     }
 }
 
+fn reset_combat_flux(mut combat_flux: ResMut<CombatFlux>) {
+    *combat_flux = CombatFlux::default();
+}
+
 fn calculate_combat_flux(
     mut combat_flux: ResMut<CombatFlux>,
     time: Res<Time>,
-    q_player_units: Query<&Health, (With<SpriteActor>, Without<EnemyUnit>)>,
-    q_enemy_units: Query<&Health, (With<SpriteActor>, With<EnemyUnit>)>,
+    q_player_units: Query<&Health, With<PlayerFaction>>,
+    q_enemy_units: Query<&Health, With<EnemyFaction>>,
 ) {
     combat_flux.timer.tick(time.delta());
 
@@ -119,38 +124,15 @@ fn calculate_combat_flux(
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PositiveMoraleEvent {
-    Massacre,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum NegativeMoraleEvent {
-    Wipeout,
-}
-
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BattleTrigger {
     RapidKills,
 }
-// impl BattleTrigger {
-//     pub fn to_positive(&self) -> PositiveMoraleEvent {
-//         match self {
-//             BattleTrigger::RapidKills => PositiveMoraleEvent::Massacre,
-//         }
-//     }
-
-//     pub fn to_negative(&self) -> NegativeMoraleEvent {
-//         match self {
-//             BattleTrigger::RapidKills => NegativeMoraleEvent::Wipeout,
-//         }
-//     }
-// }
 
 #[derive(Message)]
 pub struct CombatMessage {
-    pub _trigger: BattleTrigger,
-    pub _source: Faction,
+    pub trigger: BattleTrigger,
+    /// The side that caused the trigger.
+    pub source: Faction,
     pub _value: f32,
 }
@@ -3,10 +3,14 @@ pub(crate) use combat_flux::*;
 
 mod rapid_kills;
 
+mod squad_morale;
+pub(crate) use squad_morale::*;
+
 mod unit_reduce;
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     combat_flux::plugin(app);
     rapid_kills::plugin(app);
+    squad_morale::plugin(app);
     unit_reduce::plugin(app);
 }
@@ -1,9 +1,10 @@
 use crate::{game_manager::BattleSystems, prelude::*};
 
-use super::{BattleTrigger, CombatFlux, CombatMessage, Faction};
+use super::{BattleTrigger, CombatFlux, CombatMessage};
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.init_resource::<RapidKillsHeat>();
+    app.add_systems(OnEnter(GameState::Battle), reset_kill_heat);
     app.add_systems(
         Update,
         accumulate_kill_heat
@@ -71,6 +72,10 @@ impl RapidKillsHeat {
 // Systems
 // ============================================================================
 
+fn reset_kill_heat(mut rapid_kills: ResMut<RapidKillsHeat>) {
+    *rapid_kills = RapidKillsHeat::default();
+}
+
 /// Accumulates heat when units die
 fn accumulate_kill_heat(
     mut rapid_kills: ResMut<RapidKillsHeat>,
@@ -157,8 +162,8 @@ fn check_rapid_kill_trigger(
             rapid_kills.player_heat, enemy_threshold
         );
         ev_combat.write(CombatMessage {
-            _trigger: BattleTrigger::RapidKills,
-            _source: Faction::Player,
+            trigger: BattleTrigger::RapidKills,
+            source: Faction::Player,
             _value: rapid_kills.player_heat,
         });
         // Reset heat after triggering
@@ -181,8 +186,8 @@ fn check_rapid_kill_trigger(
             rapid_kills.enemy_heat, player_threshold
         );
         ev_combat.write(CombatMessage {
-            _trigger: BattleTrigger::RapidKills,
-            _source: Faction::Enemy,
+            trigger: BattleTrigger::RapidKills,
+            source: Faction::Enemy,
             _value: rapid_kills.enemy_heat,
         });
         // Reset heat after triggering
@@ -0,0 +1,288 @@
+use crate::{game_manager::BattleSystems, prelude::*};
+
+use super::{BattleTrigger, CombatFlux, CombatMessage};
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(OnEnter(GameState::Battle), record_squad_origins);
+    app.add_systems(
+        Update,
+        (
+            lose_morale_on_losses,
+            shift_morale_on_rapid_kills,
+            recover_morale,
+            update_morale_state,
+        )
+            .chain()
+            .in_set(BattleSystems::CalculateCombatFlux)
+            .after(AttackSet::DeathRecord)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+pub const MAX_MORALE: f32 = 100.0;
+
+/// Below this a squad wavers and attacks slower.
+pub const WAVER_THRESHOLD: f32 = 60.0;
+
+/// Below this a squad routs: it stops fighting and flees to its origin.
+pub const ROUT_THRESHOLD: f32 = 25.0;
+
+/// A routing squad rallies once its morale has recovered to this.
+const RALLY_THRESHOLD: f32 = 50.0;
+
+/// Seconds a rallied squad stands firm before it can waver again.
+const RALLY_SECONDS: f32 = 6.0;
+
+/// Share of morale losses a rallied squad still takes.
+const RALLIED_SHOCK_SHARE: f32 = 0.5;
+
+/// Attack rate of a wavering squad's units.
+const WAVERING_ATTACK_RATE: f32 = 0.6;
+
+/// Morale lost when a squad crosses the 30%, 60% and 90% loss thresholds.
+const LOSS_SHOCK: [(u8, f32); 3] = [(30, 15.0), (60, 25.0), (90, 40.0)];
+
+/// Morale gained by every squad of a side that scores rapid kills.
+const RAPID_KILL_BOOST: f32 = 10.0;
+
+/// Morale lost by every squad of the side suffering rapid kills.
+const RAPID_KILL_SHOCK: f32 = 15.0;
+
+/// Morale recovered per second while fighting.
+const RECOVERY_PER_SECOND: f32 = 2.0;
+
+/// Morale recovered per second while routing, out of the fight.
+const ROUTING_RECOVERY_PER_SECOND: f32 = 5.0;
+
+/// Extra recovery per second for each point of [`CombatFlux`] momentum in the side's favour.
+const FLUX_RECOVERY_SHARE: f32 = 0.03;
+
+// ============================================================================
+// Components
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum MoraleState {
+    #[default]
+    Steady,
+    /// Shaken: units attack slower.
+    Wavering,
+    /// Broken: units stop fighting and flee to the squad's origin.
+    Routing,
+    /// Back from a rout and standing firm for a while.
+    Rallied,
+}
+
+impl MoraleState {
+    /// The state a squad that isn't routing or rallied would be in at `value`.
+    pub fn for_value(value: f32) -> Self {
+        if value < ROUT_THRESHOLD {
+            MoraleState::Routing
+        } else if value < WAVER_THRESHOLD {
+            MoraleState::Wavering
+        } else {
+            MoraleState::Steady
+        }
+    }
+}
+
+/// How willing a squad still is to fight, from 0 to [`MAX_MORALE`].
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct SquadMorale {
+    value: f32,
+    state: MoraleState,
+    /// Seconds left of a rally.
+    rallied_for: f32,
+}
+
+impl Default for SquadMorale {
+    fn default() -> Self {
+        Self {
+            value: MAX_MORALE,
+            state: MoraleState::Steady,
+            rallied_for: 0.0,
+        }
+    }
+}
+
+impl SquadMorale {
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn state(&self) -> MoraleState {
+        self.state
+    }
+
+    pub fn is_routing(&self) -> bool {
+        self.state == MoraleState::Routing
+    }
+
+    pub fn add(&mut self, delta: f32) {
+        self.value = (self.value + delta).clamp(0.0, MAX_MORALE);
+    }
+
+    /// Takes a blow to morale, softened while rallied.
+    pub fn shock(&mut self, amount: f32) {
+        let share = if self.state == MoraleState::Rallied {
+            RALLIED_SHOCK_SHARE
+        } else {
+            1.0
+        };
+        self.add(-amount * share);
+    }
+
+    /// How fast the squad's units ready their attacks, as a share of their normal rate.
+    pub fn attack_rate(&self) -> f32 {
+        match self.state {
+            MoraleState::Steady | MoraleState::Rallied => 1.0,
+            MoraleState::Wavering => WAVERING_ATTACK_RATE,
+            MoraleState::Routing => 0.0,
+        }
+    }
+
+    fn next_state(&mut self, delta_secs: f32) -> MoraleState {
+        match self.state {
+            MoraleState::Routing if self.value >= RALLY_THRESHOLD => {
+                self.rallied_for = RALLY_SECONDS;
+                MoraleState::Rallied
+            }
+            MoraleState::Routing => MoraleState::Routing,
+            MoraleState::Rallied if self.value >= ROUT_THRESHOLD && self.rallied_for > 0.0 => {
+                self.rallied_for -= delta_secs;
+                MoraleState::Rallied
+            }
+            _ => MoraleState::for_value(self.value),
+        }
+    }
+}
+
+/// Attack rate of a unit, following its squad's morale.
+pub fn squad_attack_rate(belong_to: Option<&BelongToSquad>, q_morale: &Query<&SquadMorale>) -> f32 {
+    belong_to
+        .and_then(|belong_to| q_morale.get(belong_to.0).ok())
+        .map_or(1.0, SquadMorale::attack_rate)
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Routing squads flee to where they stood when the battle started,
+/// which may differ from where they were first placed.
+fn record_squad_origins(
+    mut commands: Commands,
+    q_squads: Query<(Entity, &Transform), With<Squad>>,
+) {
+    for (entity, transform) in &q_squads {
+        commands
+            .entity(entity)
+            .insert(SquadOriginPosition(transform.translation.truncate()));
+    }
+}
+
+fn lose_morale_on_losses(
+    mut loss_messages: MessageReader<SquadLossThresholdMessage>,
+    mut q_morale: Query<&mut SquadMorale>,
+) {
+    for msg in loss_messages.read() {
+        let Ok(mut morale) = q_morale.get_mut(msg.squad_entity) else {
+            continue;
+        };
+        let Some(&(_, shock)) = LOSS_SHOCK
+            .iter()
+            .find(|(threshold, _)| *threshold == msg.loss_percentage)
+        else {
+            continue;
+        };
+        morale.shock(shock);
+        debug!(
+            "[Morale] Squad {:?} lost {}% ({}/{} left), morale now {:.0}",
+            msg.squad_entity, msg.loss_percentage, msg.remaining_units, msg.max_units, morale.value
+        );
+    }
+}
+
+fn shift_morale_on_rapid_kills(
+    mut combat_messages: MessageReader<CombatMessage>,
+    mut q_morale: Query<(&mut SquadMorale, &Faction)>,
+) {
+    for msg in combat_messages.read() {
+        match msg.trigger {
+            BattleTrigger::RapidKills => {
+                for (mut morale, faction) in &mut q_morale {
+                    if *faction == msg.source {
+                        morale.add(RAPID_KILL_BOOST);
+                    } else {
+                        morale.shock(RAPID_KILL_SHOCK);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Morale creeps back over time, faster out of the fight and when the battle is going well.
+fn recover_morale(
+    time: Res<Time>,
+    combat_flux: Res<CombatFlux>,
+    mut q_morale: Query<(&mut SquadMorale, &Faction)>,
+) {
+    let delta = time.delta_secs();
+    for (mut morale, faction) in &mut q_morale {
+        let base = if morale.is_routing() {
+            ROUTING_RECOVERY_PER_SECOND
+        } else {
+            RECOVERY_PER_SECOND
+        };
+        // Positive flux favours the player.
+        let momentum = match faction {
+            Faction::Player => combat_flux.morale(),
+            Faction::Enemy => -combat_flux.morale(),
+        };
+        morale.add((base + momentum * FLUX_RECOVERY_SHARE) * delta);
+    }
+}
+
+fn update_morale_state(time: Res<Time>, mut q_morale: Query<(Entity, &mut SquadMorale)>) {
+    let delta = time.delta_secs();
+    for (entity, mut morale) in &mut q_morale {
+        let next = morale.next_state(delta);
+        if next != morale.state {
+            info!(
+                "[Morale] Squad {:?}: {:?} -> {:?} ({:.0})",
+                entity, morale.state, next, morale.value
+            );
+            morale.state = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heavy_losses_rout_and_recovery_rallies() {
+        let mut morale = SquadMorale::default();
+        for (_, shock) in LOSS_SHOCK {
+            morale.shock(shock);
+        }
+        morale.state = morale.next_state(0.0);
+        assert_eq!(morale.state, MoraleState::Routing);
+        assert_eq!(morale.attack_rate(), 0.0);
+
+        morale.add(RALLY_THRESHOLD);
+        morale.state = morale.next_state(0.0);
+        assert_eq!(morale.state, MoraleState::Rallied);
+
+        // The rally wears off into whatever the morale now says.
+        morale.state = morale.next_state(RALLY_SECONDS + 1.0);
+        morale.state = morale.next_state(0.0);
+        assert_eq!(morale.state, MoraleState::for_value(morale.value));
+    }
+}
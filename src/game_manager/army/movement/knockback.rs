@@ -0,0 +1,118 @@
+//! Hit reactions: knockback that shoves units by their weight, and short staggers.
+//!
+//! Pushes pile up on the unit's [`Knockback`] and come out a little every frame in
+//! `separation_system`, so a big shove reads as a slide rather than a teleport.
+
+use crate::prelude::*;
+
+/// Pixels of knockback per point of damage a hit deals.
+const HIT_IMPULSE_PER_DAMAGE: f32 = 1.5;
+
+/// How much weight resists knockback: a unit of weight `w` moves `1 / (1 + w * this)`.
+const WEIGHT_RESISTANCE: f32 = 4.0;
+
+/// Most knockback a unit can have waiting, in pixels.
+const MAX_PENDING_KNOCKBACK: f32 = 150.0;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_observer(on_knockback);
+    app.add_observer(knock_back_on_hit);
+    app.add_systems(
+        Update,
+        tick_stagger
+            .after(MovementSet::Movement)
+            .before(AttackSet::Attack),
+    );
+}
+
+/// Shoves `target` by `impulse` pixels, less the more it weighs, and staggers it for up
+/// to `stagger` seconds.
+#[derive(Event)]
+pub struct KnockbackEvent {
+    pub target: Entity,
+    pub impulse: Vec2,
+    pub stagger: f32,
+}
+
+/// Knockback still to be resolved, in pixels.
+#[derive(Component, Default, Debug, Reflect)]
+pub struct Knockback {
+    pub pending: Vec2,
+}
+
+/// Seconds the unit is still reeling from a blow and can't attack.
+#[derive(Component, Default, Debug, Reflect)]
+pub struct Stagger {
+    pub remaining: f32,
+}
+
+impl Stagger {
+    pub fn is_reeling(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
+
+/// Share of a push a unit of `weight` (its `UnitCollider::push_strength`) gives way to.
+fn knockback_share(weight: f32) -> f32 {
+    1.0 / (1.0 + weight.max(0.0) * WEIGHT_RESISTANCE)
+}
+
+fn on_knockback(
+    trigger: On<KnockbackEvent>,
+    mut q_units: Query<(&UnitCollider, &mut Knockback, &mut Stagger)>,
+) {
+    let Ok((collider, mut knockback, mut stagger)) = q_units.get_mut(trigger.target) else {
+        return;
+    };
+    let share = knockback_share(collider.push_strength);
+    knockback.pending =
+        (knockback.pending + trigger.impulse * share).clamp_length_max(MAX_PENDING_KNOCKBACK);
+    stagger.remaining = stagger.remaining.max(trigger.stagger * share);
+}
+
+/// Every hit shoves its target away from whatever dealt it.
+fn knock_back_on_hit(
+    trigger: On<AttackEvent>,
+    q_transform: Query<&GlobalTransform>,
+    mut commands: Commands,
+) {
+    let Ok([from, to]) = q_transform.get_many([trigger._from, trigger.to]) else {
+        return;
+    };
+    let direction = (to.translation() - from.translation())
+        .truncate()
+        .normalize_or_zero();
+    commands.trigger(KnockbackEvent {
+        target: trigger.to,
+        impulse: direction * trigger.damage * HIT_IMPULSE_PER_DAMAGE,
+        stagger: 0.0,
+    });
+}
+
+/// Staggered units lose their swing until they recover.
+fn tick_stagger(time: Res<Time>, mut query: Query<(&mut Stagger, &mut UnitAction)>) {
+    let delta = time.delta_secs();
+    for (mut stagger, mut state) in &mut query {
+        if !stagger.is_reeling() {
+            continue;
+        }
+        stagger.remaining -= delta;
+        if *state == UnitAction::Attacking {
+            *state = UnitAction::Idle;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heavy_units_give_way_less() {
+        let shield = knockback_share(1.0);
+        let archer = knockback_share(0.2);
+
+        assert!(shield < archer);
+        assert_eq!(knockback_share(0.0), 1.0);
+    }
+}
@@ -2,13 +2,20 @@ const UNIT_SIZE_1080: f32 = 100.0;
 use bevy::platform::collections::HashMap;
 
 use crate::prelude::*;
+mod navigation;
+use navigation::FlowFields;
+pub(crate) use navigation::{NAV_CELL_SIZE, NavGrid};
+mod targeting;
+pub(crate) use targeting::TargetPolicy;
+mod knockback;
+pub(crate) use knockback::*;
 // ============================================================================
 // Plugin
 // ============================================================================
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
-    // Init spatial grid resource
-    app.init_resource::<UnitSpatialGrid>();
+    headless_plugin(app);
+
     app.init_resource::<ShowSpatialGrid>();
     app.init_resource::<UnitInspector>();
     app.init_resource::<NeighborCellScan>();
@@ -39,6 +46,16 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
             .chain()
             .run_if(in_state(GameState::Battle)),
     );
+}
+
+/// Movement and targeting systems that don't need a window, input or gizmos.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    // Init spatial grid resource
+    app.init_resource::<UnitSpatialGrid>();
+    app.init_resource::<TargetSearchFrame>();
+    app.add_systems(OnEnter(GameState::Battle), reset_target_search_frame);
+    navigation::plugin(app);
+    knockback::plugin(app);
 
     app.add_systems(
         Update,
@@ -49,24 +66,30 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
                 commands.entity(entity).remove::<ChildOf>();
             }
         })
+        .before(MovementSet::VelocityTracking)
         .run_if(in_state(GameState::Battle)),
     );
     // Add velocity tracking systems
     app.add_systems(
         Update,
-        (init_velocity_tracking, update_velocity_from_movement)
+        (
+            init_velocity_tracking,
+            record_home_position,
+            update_velocity_from_movement,
+        )
             .chain()
             .in_set(MovementSet::VelocityTracking),
     );
 
     // Configure system ordering:
-    // VelocityTracking -> SpatialGridUpdate -> TargetFinding -> Movement -> Separation
+    // VelocityTracking -> SpatialGridUpdate -> TargetFinding -> Pathfinding -> Movement -> Separation
     app.configure_sets(
         Update,
         (
             MovementSet::VelocityTracking,
             MovementSet::SpatialGridUpdate,
             MovementSet::TargetFinding,
+            MovementSet::Pathfinding,
             MovementSet::Movement,
             MovementSet::Separation,
         )
@@ -79,6 +102,11 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
         (
             update_spatial_grid.in_set(MovementSet::SpatialGridUpdate),
             target_finding_system.in_set(MovementSet::TargetFinding),
+            targeting::support_target_finding_system.in_set(MovementSet::TargetFinding),
+            advance_target_search_frame
+                .after(MovementSet::TargetFinding)
+                .run_if(in_state(GameState::Battle)),
+            request_flow_fields.in_set(MovementSet::Pathfinding),
             movement_and_state_system.in_set(MovementSet::Movement),
             separation_system.in_set(MovementSet::Separation),
         ),
@@ -102,6 +130,10 @@ pub struct Melee;
 
 #[derive(Component, Default, PartialEq, Clone, Copy, Debug, Reflect)]
 pub struct Ranged;
+
+/// Heals allies instead of attacking enemies.
+#[derive(Component, Default, PartialEq, Clone, Copy, Debug, Reflect)]
+pub struct Support;
 /// Unit state machine for movement and combat behavior.
 #[derive(Component, Default, PartialEq, Clone, Copy, Debug, Reflect)]
 pub enum UnitAction {
@@ -128,6 +160,9 @@ pub struct CombatAttributes {
     pub unity_kind: UnitKind,
     /// The unit kind this unit counters, if any.
     pub counter: Option<UnitKind>,
+    /// Kind of damage the unit's attacks deal.
+    pub damage_type: DamageType,
+    pub resistances: Resistances,
 }
 
 impl CombatAttributes {
@@ -140,6 +175,8 @@ impl CombatAttributes {
             defense: 0.0,
             unity_kind: unity_type,
             counter: None,
+            damage_type: DamageType::Slash,
+            resistances: Resistances::default(),
         }
     }
     pub fn ranged(unity_type: UnitKind) -> Self {
@@ -151,6 +188,8 @@ impl CombatAttributes {
             defense: 0.0,
             unity_kind: unity_type,
             counter: None,
+            damage_type: DamageType::Pierce,
+            resistances: Resistances::default(),
         }
     }
 }
@@ -177,6 +216,10 @@ impl Default for UnitCollider {
 #[derive(Component, Default, Debug, Reflect)]
 pub struct Target(pub Option<Entity>);
 
+/// Where the unit stood when the battle started; holding and retreating units fall back to it.
+#[derive(Component, Debug, Reflect)]
+pub struct HomePosition(pub Vec2);
+
 // ============================================================================
 // Uniform Spatial Hash Grid
 // ============================================================================
@@ -198,6 +241,7 @@ fn world_to_grid(pos: Vec2) -> (i32, i32) {
 #[derive(Clone, Copy)]
 pub(crate) struct GridUnit {
     pub entity: Entity,
+    pub id: UnitId,
     pub pos: Vec2,
     pub radius: f32,
     pub push_strength: f32,
@@ -305,6 +349,29 @@ impl UnitSpatialGrid {
 
         best.map(|(e, _)| e)
     }
+
+    /// Calls `f` with every unit whose collider reaches within `radius` of `pos`,
+    /// and its distance from `pos`.
+    pub(crate) fn for_each_within(
+        &self,
+        pos: Vec2,
+        radius: f32,
+        mut f: impl FnMut(&GridUnit, f32),
+    ) {
+        let center = world_to_grid(pos);
+        // Colliders are at most half a unit across, so look that much further.
+        let reach = radius + UNIT_SIZE_1080 / 2.0;
+        let max_ring = ((reach / GRID_CELL_SIZE).ceil() as i32).min(MAX_RING_RADIUS);
+
+        for r in 0..=max_ring {
+            self.for_each_in_ring(center, r, |neighbor| {
+                let dist = pos.distance(neighbor.pos);
+                if dist <= radius + neighbor.radius {
+                    f(neighbor, dist);
+                }
+            });
+        }
+    }
 }
 
 const NEIGHBOR_OFFSETS: [(i32, i32); 9] = [
@@ -319,29 +386,36 @@ const NEIGHBOR_OFFSETS: [(i32, i32); 9] = [
     (1, 1),
 ];
 
-/// Rebuilds the spatial hash grid every frame. O(N).
+/// Rebuilds the spatial hash grid every frame. O(N log N).
 /// Dead units are despawned, so every entity in the query is alive.
+/// Each cell is kept in [`UnitId`] order, so searches visit units in the same order
+/// whatever order the query yields them in.
 fn update_spatial_grid(
     mut grid: ResMut<UnitSpatialGrid>,
-    q_units: Query<(Entity, &GlobalTransform, &UnitCollider, &Faction)>,
+    q_units: Query<(Entity, &UnitId, &GlobalTransform, &UnitCollider, &Faction)>,
 ) {
     // Clear each Vec's contents but retain its allocated capacity (zero heap allocations after warm-up).
     for vec in grid.cells.values_mut() {
         vec.clear();
     }
 
-    for (entity, global_transform, collider, faction) in q_units.iter() {
+    for (entity, id, global_transform, collider, faction) in q_units.iter() {
         let pos = global_transform.translation().truncate();
         let cell_coord = world_to_grid(pos);
 
         grid.cells.entry(cell_coord).or_default().push(GridUnit {
             entity,
+            id: *id,
             pos,
             radius: collider.radius,
             push_strength: collider.push_strength,
             faction: *faction,
         });
     }
+
+    for vec in grid.cells.values_mut() {
+        vec.sort_unstable_by_key(|unit| unit.id);
+    }
 }
 
 // ============================================================================
@@ -355,6 +429,8 @@ pub enum MovementSet {
     /// Rebuilds the spatial hash grid
     SpatialGridUpdate,
     TargetFinding,
+    /// Builds the flow fields units need to get around obstacles
+    Pathfinding,
     Movement,
     Separation,
 }
@@ -372,59 +448,224 @@ const K_NEAREST_CANDIDATES: usize = 3;
 const MELEE_THRESHOLD: f32 = 80.0;
 
 /// How many frames to spread target searches across.
-/// Units without a target are bucketed by entity index so at most 1/N do a full
+/// Units without a target are bucketed by [`UnitId`] so at most 1/N do a full
 /// grid search per frame, capping the per-frame cost at large unit counts.
 const TARGET_SLICE_COUNT: u32 = 10;
 
+/// Battle frames so far, counted from the start of each battle so replays slice the same.
+#[derive(Resource, Default)]
+struct TargetSearchFrame(u32);
+
+impl TargetSearchFrame {
+    /// Whether the unit numbered `id` gets its turn at a full search this frame.
+    fn is_turn_of(&self, id: UnitId) -> bool {
+        id.0 % TARGET_SLICE_COUNT == self.0 % TARGET_SLICE_COUNT
+    }
+}
+
+fn reset_target_search_frame(mut frame: ResMut<TargetSearchFrame>) {
+    *frame = TargetSearchFrame::default();
+}
+
+fn advance_target_search_frame(mut frame: ResMut<TargetSearchFrame>) {
+    frame.0 = frame.0.wrapping_add(1);
+}
+
+/// How far beyond its attack range a holding unit will reach from its home to engage.
+const HOLD_LEASH: f32 = 60.0;
+
+/// Retreating units further than this from home ignore the enemy and keep walking.
+const RETREAT_DISENGAGE_RADIUS: f32 = 2.0 * HOLD_LEASH;
+
+/// Units walking home stop once they are this close.
+const HOME_ARRIVAL_RADIUS: f32 = 16.0;
+
+/// Routing units stop fleeing once this close to their squad's origin.
+/// The whole squad crowds around one point, so this is much wider than [`HOME_ARRIVAL_RADIUS`].
+const ROUT_ARRIVAL_RADIUS: f32 = 200.0;
+
+/// Share of the distance to the target a flanking unit swings out sideways.
+const FLANK_SWING_SHARE: f32 = 0.5;
+
+/// Largest sideways swing of a flanking unit, in pixels.
+const FLANK_SWING_MAX: f32 = 400.0;
+
 /// Finds targets for units using the spatial hash grid.
 ///
 /// Execution order per unit:
-/// 1. Debounce  – current target still in melee range → keep it, skip all work.
-/// 2. Valid target check – still has a living target → skip all work.
-/// 3. Time slicing – only 1/TARGET_SLICE_COUNT of targetless units search per frame.
-/// 4. Melee Proximity Override – snap to any enemy within MELEE_THRESHOLD.
-/// 5. Full grid search – expanding ring until an enemy is found.
+/// 0. Morale – units of a routing squad drop their target and flee.
+/// 1. Stance – holding and retreating units only look around their [`HomePosition`].
+/// 2. Debounce  – current target still in melee range → keep it, skip all work.
+/// 3. Valid target check – still has a living target (of the focused squad, if any) → skip all work.
+/// 4. Time slicing – only 1/TARGET_SLICE_COUNT of targetless units search per frame.
+/// 5. Melee Proximity Override – snap to any enemy within MELEE_THRESHOLD.
+/// 6. Focus – nearest unit of the focused squad.
+/// 7. Full grid search – expanding ring until an enemy is found, picked by [`TargetPolicy`].
 fn target_finding_system(
     grid: Res<UnitSpatialGrid>,
-    mut frame: Local<u32>,
-    mut q_units: Query<(Entity, &Transform, &mut Target, &Faction, &CombatAttributes)>,
+    frame: Res<TargetSearchFrame>,
+    mut q_units: Query<
+        (
+            Entity,
+            &UnitId,
+            &Transform,
+            &mut Target,
+            &Faction,
+            &CombatAttributes,
+            Option<&BelongToSquad>,
+            Option<&HomePosition>,
+            Option<&TargetPolicy>,
+        ),
+        Without<Support>,
+    >,
+    q_candidates: Query<(&Health, &CombatAttributes)>,
     q_pawn: Query<&Pawn>,
     q_transform: Query<&Transform>,
+    q_belong: Query<&BelongToSquad>,
+    q_squad: Query<(&Squad, &RootStationSquad)>,
+    q_squad_morale: Query<(&SquadMorale, &GlobalTransform, Option<&SquadOriginPosition>)>,
 ) {
-    let current_frame = *frame;
-    *frame = frame.wrapping_add(1);
-
-    for (entity, transform, mut target, faction, _stats) in &mut q_units {
+    for (entity, id, transform, mut target, faction, stats, belong_to, home, policy) in &mut q_units
+    {
         let my_pos = transform.translation.truncate();
+        let home = home.map_or(my_pos, |home| home.0);
+        let (stance, focus) = squad_orders(belong_to, &q_squad);
+
+        // 0. Routing units don't fight
+        if flee_point(belong_to, &q_squad_morale).is_some() {
+            target.0 = None;
+            continue;
+        }
 
-        // 1. Debounce: current target is alive and already in melee range → keep it
+        // 1. Holding and retreating units never leave their post to chase
+        match stance {
+            Stance::Retreat if my_pos.distance_squared(home) > RETREAT_DISENGAGE_RADIUS.powi(2) => {
+                target.0 = None;
+                continue;
+            }
+            Stance::HoldPosition | Stance::Retreat => {
+                let reach = stats.attack_range + HOLD_LEASH;
+                let in_reach = target
+                    .0
+                    .and_then(|target_entity| q_transform.get(target_entity).ok())
+                    .is_some_and(|target_transform| {
+                        target_transform
+                            .translation
+                            .truncate()
+                            .distance_squared(home)
+                            <= reach * reach
+                    });
+                if !in_reach {
+                    target.0 = grid.find_nearest_enemy(entity, home, *faction, reach);
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        // 2. Debounce: current target is alive and already in melee range → keep it
         if is_current_target_in_melee_range(my_pos, &target, &q_transform) {
             continue;
         }
 
-        // 2. Still has a valid living target → nothing to do
-        if !needs_new_target(&target, &q_pawn) {
+        // 3. Still has a valid living target → nothing to do
+        let focus_units = focus
+            .and_then(|focus| q_squad.get(focus).ok())
+            .map(|(_, units)| units);
+        let off_focus = focus_units.is_some_and(|units| !units.is_empty())
+            && target
+                .0
+                .and_then(|target_entity| q_belong.get(target_entity).ok())
+                .is_none_or(|belong_to| Some(belong_to.0) != focus);
+        if !off_focus && !needs_new_target(&target, &q_pawn) {
             continue;
         }
 
-        // 3. Time slicing: spread targetless units across TARGET_SLICE_COUNT frames
+        // 4. Time slicing: spread targetless units across TARGET_SLICE_COUNT frames
         //    to avoid thousands of full grid searches in a single frame.
-        if entity.index_u32() % TARGET_SLICE_COUNT != current_frame % TARGET_SLICE_COUNT {
+        if !frame.is_turn_of(*id) {
             continue;
         }
 
-        // 4. Melee Proximity Override: snap to any enemy already inside melee range
+        // 5. Melee Proximity Override: snap to any enemy already inside melee range
         if let Some(new_target) = grid.find_nearest_enemy(entity, my_pos, *faction, MELEE_THRESHOLD)
         {
             target.0 = Some(new_target);
             continue;
         }
 
-        // 5. Full grid search
-        target.0 = find_target_from_grid(entity, my_pos, *faction, &grid);
+        // 6. Focus: the nearest unit of the focused squad, while it has any left
+        if let Some(new_target) =
+            focus_units.and_then(|units| find_nearest_unit(my_pos, units, &q_transform))
+        {
+            target.0 = Some(new_target);
+            continue;
+        }
+
+        // 7. Full grid search, by the unit's targeting policy
+        target.0 = match policy.copied().unwrap_or_default() {
+            TargetPolicy::Nearest => find_target_from_grid(entity, *id, my_pos, *faction, &grid),
+            policy => targeting::find_policy_target(
+                policy,
+                entity,
+                my_pos,
+                *faction,
+                stats,
+                &grid,
+                &q_candidates,
+            )
+            .or_else(|| find_target_from_grid(entity, *id, my_pos, *faction, &grid)),
+        };
+    }
+}
+
+/// The stance of a unit's squad and the squad it focuses.
+/// `Focus` without a squad to focus is treated as `Advance`.
+fn squad_orders(
+    belong_to: Option<&BelongToSquad>,
+    q_squad: &Query<(&Squad, &RootStationSquad)>,
+) -> (Stance, Option<Entity>) {
+    let Some((squad, _)) = belong_to.and_then(|belong_to| q_squad.get(belong_to.0).ok()) else {
+        return (Stance::Advance, None);
+    };
+    match (squad.stance, squad.focus) {
+        (Stance::Focus, Some(focus)) => (Stance::Focus, Some(focus)),
+        (Stance::Focus, None) => (Stance::Advance, None),
+        (stance, _) => (stance, None),
     }
 }
 
+/// Where a unit of a routing squad flees to, or `None` while its squad holds together.
+fn flee_point(
+    belong_to: Option<&BelongToSquad>,
+    q_squad_morale: &Query<(&SquadMorale, &GlobalTransform, Option<&SquadOriginPosition>)>,
+) -> Option<Vec2> {
+    let (morale, transform, origin) = q_squad_morale.get(belong_to?.0).ok()?;
+    if !morale.is_routing() {
+        return None;
+    }
+    Some(origin.map_or(transform.translation().truncate(), |origin| origin.0))
+}
+
+/// The living unit of `units` closest to `my_pos`.
+fn find_nearest_unit(
+    my_pos: Vec2,
+    units: &RootStationSquad,
+    q_transform: &Query<&Transform>,
+) -> Option<Entity> {
+    units
+        .iter()
+        .filter_map(|&unit| {
+            let transform = q_transform.get(unit).ok()?;
+            Some((
+                unit,
+                my_pos.distance_squared(transform.translation.truncate()),
+            ))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(unit, _)| unit)
+}
+
 /// Checks if the current target is within melee range.
 /// Dead units are despawned, so a missing entity means the target is gone.
 fn is_current_target_in_melee_range(
@@ -460,17 +701,61 @@ fn needs_new_target(target: &Target, q_unit: &Query<&Pawn>) -> bool {
 /// Strategy:
 /// 1. Expand outward ring by ring until we find enemies
 /// 2. Collect candidates from the found ring + 1 extra ring (boundary)
-/// 3. Use entity index as a deterministic "jitter" to spread target selection
-// Grid contains only alive units (update_spatial_grid skips Dead), so no state check needed.
+/// 3. Use the unit's [`UnitId`] as a deterministic "jitter" to spread target selection
 fn find_target_from_grid(
     self_entity: Entity,
+    self_id: UnitId,
     my_pos: Vec2,
     my_faction: Faction,
     grid: &UnitSpatialGrid,
 ) -> Option<Entity> {
+    let mut candidates = nearest_candidates(self_entity, my_pos, my_faction, grid);
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    if candidates.len() == 1 {
+        return Some(candidates[0].0.entity);
+    }
+
+    // Sort by distance, nearest id first on ties, and take top K
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.id.cmp(&b.0.id)));
+    candidates.truncate(K_NEAREST_CANDIDATES);
+
+    // Anti-convergence heuristic:
+    // Use the querying unit's id to deterministically pick from top candidates.
+    let self_index = self_id.0 as usize;
+
+    let min_dist_sq = candidates[0].1;
+    let min_dist = min_dist_sq.sqrt();
+    let distance_threshold_sq = ((min_dist * 1.2).max(min_dist + 64.0)).powi(2);
+
+    let top_tier: Vec<_> = candidates
+        .iter()
+        .filter(|(_, dist_sq)| *dist_sq <= distance_threshold_sq)
+        .collect();
+
+    if top_tier.is_empty() {
+        return Some(candidates[0].0.entity);
+    }
+
+    let pick_index = self_index % top_tier.len();
+    Some(top_tier[pick_index].0.entity)
+}
+
+/// Enemies in the first ring around `my_pos` that has any, plus the ring after it,
+/// with their squared distances.
+// Grid contains only alive units (update_spatial_grid skips Dead), so no state check needed.
+fn nearest_candidates(
+    self_entity: Entity,
+    my_pos: Vec2,
+    my_faction: Faction,
+    grid: &UnitSpatialGrid,
+) -> Vec<(GridUnit, f32)> {
     let my_cell = world_to_grid(my_pos);
 
-    let mut candidates: Vec<(Entity, f32)> = Vec::new();
+    let mut candidates: Vec<(GridUnit, f32)> = Vec::new();
     let mut found_ring: Option<i32> = None;
 
     for r in 0..=MAX_RING_RADIUS {
@@ -490,7 +775,7 @@ fn find_target_from_grid(
             }
 
             let dist_sq = my_pos.distance_squared(neighbor.pos);
-            candidates.push((neighbor.entity, dist_sq));
+            candidates.push((*neighbor, dist_sq));
         });
 
         if !candidates.is_empty() && found_ring.is_none() {
@@ -498,37 +783,7 @@ fn find_target_from_grid(
         }
     }
 
-    if candidates.is_empty() {
-        return None;
-    }
-
-    if candidates.len() == 1 {
-        return Some(candidates[0].0);
-    }
-
-    // Sort by distance and take top K
-    candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-    candidates.truncate(K_NEAREST_CANDIDATES);
-
-    // Anti-convergence heuristic:
-    // Use the querying entity's index to deterministically pick from top candidates.
-    let self_index = self_entity.index_u32() as usize;
-
-    let min_dist_sq = candidates[0].1;
-    let min_dist = min_dist_sq.sqrt();
-    let distance_threshold_sq = ((min_dist * 1.2).max(min_dist + 64.0)).powi(2);
-
-    let top_tier: Vec<_> = candidates
-        .iter()
-        .filter(|(_, dist_sq)| *dist_sq <= distance_threshold_sq)
-        .collect();
-
-    if top_tier.is_empty() {
-        return Some(candidates[0].0);
-    }
-
-    let pick_index = self_index % top_tier.len();
-    Some(top_tier[pick_index].0)
+    candidates
 }
 
 /// Radius within which nearby allies exert a lateral separation steering force.
@@ -586,6 +841,8 @@ fn compute_ally_separation(entity: Entity, pos: Vec2, grid: &UnitSpatialGrid) ->
 fn movement_and_state_system(
     time: Res<Time>,
     grid: Res<UnitSpatialGrid>,
+    nav: Res<NavGrid>,
+    flow_fields: Res<FlowFields>,
     mut q_units: Query<(
         Entity,
         &mut Transform,
@@ -594,15 +851,50 @@ fn movement_and_state_system(
         &Target,
         &CombatAttributes,
         &UnitCollider,
+        &Faction,
+        Option<&BelongToSquad>,
+        Option<&HomePosition>,
+        Option<&Stagger>,
     )>,
     q_targets: Query<(&GlobalTransform, &UnitCollider)>,
+    q_squad: Query<(&Squad, &RootStationSquad)>,
+    q_squad_morale: Query<(&SquadMorale, &GlobalTransform, Option<&SquadOriginPosition>)>,
 ) {
     let delta_secs = time.delta_secs();
     q_units.par_iter_mut().for_each(
-        |(entity, mut transform, global_transform, mut state, target, stats, collider)| {
+        |(
+            entity,
+            mut transform,
+            global_transform,
+            mut state,
+            target,
+            stats,
+            collider,
+            faction,
+            belong_to,
+            home,
+            stagger,
+        )| {
+            // Use GlobalTransform for world-space position (units are children of squads)
+            let my_pos = global_transform.translation().truncate();
+            let (stance, _) = squad_orders(belong_to, &q_squad);
+
             let Some(target_entity) = target.0 else {
-                // No target - go idle
-                state.set_if_neq(UnitAction::Idle);
+                // No target - routing units flee, holding and retreating units walk back home,
+                // the rest go idle
+                let Some((goal, _)) = fallback_goal(belong_to, stance, home, &q_squad_morale)
+                    .filter(|(goal, radius)| my_pos.distance_squared(*goal) > radius * radius)
+                else {
+                    state.set_if_neq(UnitAction::Idle);
+                    return;
+                };
+                state.set_if_neq(UnitAction::Moving);
+                let waypoint = flow_fields.waypoint(&nav, *faction, my_pos, goal);
+                let movement = steer(entity, my_pos, waypoint, stats, &grid) * delta_secs;
+                let movement = nav.constrain(my_pos, movement);
+                transform.translation.x += movement.x;
+                transform.translation.y += movement.y;
+                face_target(&mut transform, my_pos, goal);
                 return;
             };
 
@@ -611,8 +903,6 @@ fn movement_and_state_system(
                 return;
             };
 
-            // Use GlobalTransform for world-space position (units are children of squads)
-            let my_pos = global_transform.translation().truncate();
             let target_pos = target_transform.translation().truncate();
 
             // 2D distance only (X and Y) as per project rules
@@ -628,10 +918,11 @@ fn movement_and_state_system(
             let stop_buffer = 2.0;
             let stop_threshold = stats.attack_range.max(combined_radii + stop_buffer);
 
-            // Hysteresis: If already attacking, allow being pushed back slightly without
-            // immediately switching back to Moving. This prevents oscillation between
-            // Movement (pulling in) and Separation (pushing out) systems.
-            let hysteresis_buffer = if *state == UnitAction::Attacking {
+            // Hysteresis: If already attacking, or staggered out of it, allow being pushed
+            // back slightly without immediately switching back to Moving. This prevents
+            // oscillation between Movement (pulling in) and Separation (pushing out) systems.
+            let reeling = stagger.is_some_and(Stagger::is_reeling);
+            let hysteresis_buffer = if *state == UnitAction::Attacking || reeling {
                 5.0
             } else {
                 0.0
@@ -641,25 +932,22 @@ fn movement_and_state_system(
                 dist_to_target_sq <= (threshold_with_hysteresis * threshold_with_hysteresis);
 
             if is_in_range {
-                // Close enough to attack
-                state.set_if_neq(UnitAction::Attacking);
+                // Close enough to attack, once it has stopped reeling from a blow
+                state.set_if_neq(if reeling {
+                    UnitAction::Idle
+                } else {
+                    UnitAction::Attacking
+                });
                 // Face the target even while attacking
                 face_target(&mut transform, my_pos, target_pos);
             } else {
                 // Need to move closer
                 state.set_if_neq(UnitAction::Moving);
 
-                // --- NOC Steering: seek + ally separation ---
-                // Desired velocity: full speed straight toward target
-                let seek = (target_pos - my_pos).normalize_or_zero() * stats.speed;
-
-                // Separation force: steer away from nearby allies (spatial grid lookup).
-                let sep = compute_ally_separation(entity, my_pos, &grid) * stats.speed;
-
-                // Blend: seek drives toward enemy, separation steers laterally around allies.
-                // clamp_length_max ensures we never exceed max speed.
-                let desired = seek + sep * ALLY_SEPARATION_WEIGHT;
-                let movement = desired.clamp_length_max(stats.speed) * delta_secs;
+                let aim_pos = flank_aim(stance, *faction, my_pos, target_pos);
+                let waypoint = flow_fields.waypoint(&nav, *faction, my_pos, aim_pos);
+                let movement = steer(entity, my_pos, waypoint, stats, &grid) * delta_secs;
+                let movement = nav.constrain(my_pos, movement);
 
                 transform.translation.x += movement.x;
                 transform.translation.y += movement.y;
@@ -671,6 +959,90 @@ fn movement_and_state_system(
     );
 }
 
+/// Flanking units aim to one side of the target, closing in as they get near.
+fn flank_aim(stance: Stance, faction: Faction, my_pos: Vec2, target_pos: Vec2) -> Vec2 {
+    stance.flank_side(faction).map_or(target_pos, |side| {
+        let swing = (my_pos.distance(target_pos) * FLANK_SWING_SHARE).min(FLANK_SWING_MAX);
+        target_pos + side * swing
+    })
+}
+
+/// Where a unit without a target heads, and how close counts as arrived: routing units flee,
+/// holding and retreating units walk back home.
+fn fallback_goal(
+    belong_to: Option<&BelongToSquad>,
+    stance: Stance,
+    home: Option<&HomePosition>,
+    q_squad_morale: &Query<(&SquadMorale, &GlobalTransform, Option<&SquadOriginPosition>)>,
+) -> Option<(Vec2, f32)> {
+    match flee_point(belong_to, q_squad_morale) {
+        Some(flee_to) => Some((flee_to, ROUT_ARRIVAL_RADIUS)),
+        None if matches!(stance, Stance::HoldPosition | Stance::Retreat) => {
+            home.map(|home| (home.0, HOME_ARRIVAL_RADIUS))
+        }
+        None => None,
+    }
+}
+
+/// Makes sure every unit whose way to its goal is blocked has a flow field to follow.
+/// Does nothing on levels without obstacles.
+fn request_flow_fields(
+    nav: Res<NavGrid>,
+    mut flow_fields: ResMut<FlowFields>,
+    q_units: Query<(
+        &GlobalTransform,
+        &Target,
+        &Faction,
+        Option<&BelongToSquad>,
+        Option<&HomePosition>,
+    )>,
+    q_targets: Query<&GlobalTransform>,
+    q_squad: Query<(&Squad, &RootStationSquad)>,
+    q_squad_morale: Query<(&SquadMorale, &GlobalTransform, Option<&SquadOriginPosition>)>,
+) {
+    if !nav.has_obstacles() {
+        return;
+    }
+
+    for (global_transform, target, faction, belong_to, home) in &q_units {
+        let my_pos = global_transform.translation().truncate();
+        let (stance, _) = squad_orders(belong_to, &q_squad);
+        let goal = match target.0.and_then(|target| q_targets.get(target).ok()) {
+            Some(target_transform) => flank_aim(
+                stance,
+                *faction,
+                my_pos,
+                target_transform.translation().truncate(),
+            ),
+            None => match fallback_goal(belong_to, stance, home, &q_squad_morale) {
+                Some((goal, _)) => goal,
+                None => continue,
+            },
+        };
+        flow_fields.request(&nav, *faction, my_pos, goal);
+    }
+}
+
+/// NOC steering velocity toward `goal`: seek blended with ally separation.
+fn steer(
+    entity: Entity,
+    my_pos: Vec2,
+    goal: Vec2,
+    stats: &CombatAttributes,
+    grid: &UnitSpatialGrid,
+) -> Vec2 {
+    // Desired velocity: full speed straight toward the goal
+    let seek = (goal - my_pos).normalize_or_zero() * stats.speed;
+
+    // Separation force: steer away from nearby allies (spatial grid lookup).
+    let sep = compute_ally_separation(entity, my_pos, grid) * stats.speed;
+
+    // Blend: seek drives toward the goal, separation steers laterally around allies.
+    // clamp_length_max ensures we never exceed max speed.
+    let desired = seek + sep * ALLY_SEPARATION_WEIGHT;
+    desired.clamp_length_max(stats.speed)
+}
+
 fn face_target(transform: &mut Transform, my_pos: Vec2, target_pos: Vec2) {
     let direction = target_pos - my_pos;
 
@@ -690,15 +1062,18 @@ const MAX_PUSH_PER_FRAME: f32 = 2.;
 
 /// Soft collision separation system using spatial hash grid.
 /// Only checks the 9 neighbouring cells instead of all units → O(N) instead of O(N²).
+/// Also lets out each unit's pending [`Knockback`], up to `MAX_PUSH_PER_FRAME` a frame.
 fn separation_system(
     time: Res<Time>,
     grid: Res<UnitSpatialGrid>,
+    nav: Res<NavGrid>,
     mut q_units: Query<(
         Entity,
         &mut Transform,
         &GlobalTransform,
         &UnitCollider,
         &UnitAction,
+        Option<&mut Knockback>,
     )>,
 ) {
     let delta = time.delta_secs();
@@ -707,7 +1082,7 @@ fn separation_system(
     }
 
     q_units.par_iter_mut().for_each(
-        |(entity, mut transform, global_transform, collider, state)| {
+        |(entity, mut transform, global_transform, collider, state, knockback)| {
             let my_pos = global_transform.translation().truncate();
             let my_cell = world_to_grid(my_pos);
             let mut total_push = Vec2::ZERO;
@@ -750,15 +1125,27 @@ fn separation_system(
                 }
             }
 
-            if total_push != Vec2::ZERO {
-                let push_len_sq = total_push.length_squared();
-                let max_push_sq = MAX_PUSH_PER_FRAME * MAX_PUSH_PER_FRAME;
+            let push_len_sq = total_push.length_squared();
+            let max_push_sq = MAX_PUSH_PER_FRAME * MAX_PUSH_PER_FRAME;
 
-                let final_push = if push_len_sq > max_push_sq {
-                    total_push.normalize() * MAX_PUSH_PER_FRAME
-                } else {
-                    total_push
-                };
+            let mut final_push = if push_len_sq > max_push_sq {
+                total_push.normalize() * MAX_PUSH_PER_FRAME
+            } else {
+                total_push
+            };
+
+            // Knockback slides out on top of the crowd's push
+            if let Some(mut knockback) =
+                knockback.filter(|knockback| knockback.pending != Vec2::ZERO)
+            {
+                let step = knockback.pending.clamp_length_max(MAX_PUSH_PER_FRAME);
+                knockback.pending -= step;
+                final_push += step;
+            }
+
+            if final_push != Vec2::ZERO {
+                // Crowds never shove units into walls
+                let final_push = nav.constrain(my_pos, final_push);
 
                 transform.translation.x += final_push.x;
                 transform.translation.y += final_push.y;
@@ -790,6 +1177,17 @@ fn init_velocity_tracking(
     }
 }
 
+/// Remembers where each unit starts the battle.
+fn record_home_position(
+    q_units: Query<(Entity, &GlobalTransform), (With<Unit>, Without<HomePosition>)>,
+    mut commands: Commands,
+) {
+    for (entity, global_transform) in &q_units {
+        let pos = global_transform.translation().truncate();
+        commands.entity(entity).insert(HomePosition(pos));
+    }
+}
+
 fn update_velocity_from_movement(
     time: Res<Time>,
     mut q_units: Query<
@@ -928,21 +1326,21 @@ fn draw_unit_inspector_gizmos(
     inspector: Res<UnitInspector>,
     grid: Res<UnitSpatialGrid>,
     mut gizmos: Gizmos,
-    q_units: Query<(Entity, &GlobalTransform, &Faction), With<Unit>>,
+    q_units: Query<(Entity, &UnitId, &GlobalTransform, &Faction), With<Unit>>,
 ) {
     if !inspector.active {
         return;
     }
 
-    // Collect and sort by entity id for a stable cycle order
+    // Collect and sort by unit id for a stable cycle order
     let mut units: Vec<_> = q_units.iter().collect();
     if units.is_empty() {
         return;
     }
-    units.sort_by_key(|(e, _, _)| e.index_u32());
+    units.sort_by_key(|(_, id, _, _)| **id);
 
     let idx = inspector.index % units.len();
-    let (selected_entity, selected_transform, selected_faction) = units[idx];
+    let (selected_entity, _, selected_transform, selected_faction) = units[idx];
     let my_pos = selected_transform.translation().truncate();
 
     // Bright yellow ring around the selected unit
@@ -1016,7 +1414,7 @@ fn trigger_neighbor_cell_scan(
     kbd: Res<ButtonInput<KeyCode>>,
     inspector: Res<UnitInspector>,
     mut scan: ResMut<NeighborCellScan>,
-    q_units: Query<(Entity, &GlobalTransform), With<Unit>>,
+    q_units: Query<(&UnitId, &GlobalTransform), With<Unit>>,
 ) {
     if !kbd.just_pressed(KeyCode::KeyG) {
         return;
@@ -1029,7 +1427,7 @@ fn trigger_neighbor_cell_scan(
     if units.is_empty() {
         return;
     }
-    units.sort_by_key(|(e, _)| e.index_u32());
+    units.sort_by_key(|(id, _)| **id);
 
     let idx = inspector.index % units.len();
     let (_, transform) = units[idx];
@@ -1051,7 +1449,7 @@ fn advance_neighbor_cell_scan(
     time: Res<Time<Real>>,
     mut scan: ResMut<NeighborCellScan>,
     inspector: Res<UnitInspector>,
-    q_units: Query<(Entity, &GlobalTransform), With<Unit>>,
+    q_units: Query<(&UnitId, &GlobalTransform), With<Unit>>,
 ) {
     if !scan.active {
         return;
@@ -1059,7 +1457,7 @@ fn advance_neighbor_cell_scan(
 
     // Keep center_pos in sync with the currently inspected unit
     let mut units: Vec<_> = q_units.iter().collect();
-    units.sort_by_key(|(e, _)| e.index_u32());
+    units.sort_by_key(|(id, _)| **id);
     if !units.is_empty() {
         let idx = inspector.index % units.len();
         scan.center_pos = units[idx].1.translation().truncate();
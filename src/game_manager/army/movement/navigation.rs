@@ -0,0 +1,303 @@
+//! Grid navigation around level obstacles.
+//!
+//! Walls and rivers from the level's IntGrid are collected into a [`NavGrid`]. A unit whose
+//! straight line to its goal is blocked follows a [`FlowField`] toward the goal's region instead.
+//! Fields are shared by every unit of a faction heading for the same region.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bevy::platform::collections::{HashMap, HashSet};
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.init_resource::<NavGrid>();
+    app.init_resource::<FlowFields>();
+    app.add_systems(
+        Update,
+        clear_flow_fields.run_if(resource_changed::<NavGrid>),
+    );
+}
+
+/// Side of a navigation cell, matching the LDtk grid.
+pub const NAV_CELL_SIZE: f32 = 64.0;
+
+/// Flow fields lead into square regions this many cells across, so units chasing
+/// nearby targets share one field.
+const REGION_CELLS: i32 = 4;
+
+/// Cached flow fields before the cache starts over.
+const MAX_FLOW_FIELDS: usize = 64;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+const NEIGHBORS: [IVec2; 8] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+    IVec2::new(1, 1),
+    IVec2::new(1, -1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, -1),
+];
+
+// ============================================================================
+// Nav grid
+// ============================================================================
+
+/// Cells units can't walk through. Empty unless the level has obstacles,
+/// in which case movement is exactly the plain seek it always was.
+#[derive(Resource, Default)]
+pub struct NavGrid {
+    /// The level the grid was built for.
+    area: Rect,
+    /// Bottom-left corner of the level, so cells line up with its grid.
+    origin: Vec2,
+    blocked: HashSet<IVec2>,
+    /// Cells inside the level, inclusive.
+    bounds: IRect,
+}
+
+impl NavGrid {
+    /// Rebuilds the grid for a level covering `area`, from the centers of its blocked cells.
+    pub fn rebuild(&mut self, area: Rect, blocked: impl IntoIterator<Item = Vec2>) {
+        self.area = area;
+        self.origin = area.min;
+        self.blocked = blocked.into_iter().map(|pos| self.cell_of(pos)).collect();
+        self.bounds = IRect::from_corners(
+            self.cell_of(area.min),
+            self.cell_of(area.max - Vec2::splat(NAV_CELL_SIZE / 2.0)),
+        );
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Centers of the blocked cells, as [`NavGrid::rebuild`] takes them.
+    pub fn blocked_cells(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.blocked.iter().map(|&cell| self.cell_center(cell))
+    }
+
+    pub fn has_obstacles(&self) -> bool {
+        !self.blocked.is_empty()
+    }
+
+    pub fn cell_of(&self, pos: Vec2) -> IVec2 {
+        ((pos - self.origin) / NAV_CELL_SIZE).floor().as_ivec2()
+    }
+
+    pub fn cell_center(&self, cell: IVec2) -> Vec2 {
+        self.origin + (cell.as_vec2() + 0.5) * NAV_CELL_SIZE
+    }
+
+    pub fn is_blocked(&self, pos: Vec2) -> bool {
+        self.blocked.contains(&self.cell_of(pos))
+    }
+
+    /// Whether a unit can walk straight from `from` to `to`.
+    pub fn is_clear_line(&self, from: Vec2, to: Vec2) -> bool {
+        if !self.has_obstacles() {
+            return true;
+        }
+        // Sampling at half a cell can't skip over a whole cell.
+        let steps = (from.distance(to) / (NAV_CELL_SIZE / 2.0)).ceil() as usize;
+        (0..=steps).all(|step| {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                step as f32 / steps as f32
+            };
+            !self.is_blocked(from.lerp(to, t))
+        })
+    }
+
+    /// Limits a move so it doesn't end in a blocked cell, sliding along walls where it can.
+    pub fn constrain(&self, from: Vec2, delta: Vec2) -> Vec2 {
+        if !self.has_obstacles() || !self.is_blocked(from + delta) {
+            return delta;
+        }
+        [Vec2::new(delta.x, 0.0), Vec2::new(0.0, delta.y)]
+            .into_iter()
+            .find(|slide| !self.is_blocked(from + *slide))
+            .unwrap_or(Vec2::ZERO)
+    }
+
+    fn can_step(&self, from: IVec2, step: IVec2) -> bool {
+        let to = from + step;
+        if self.blocked.contains(&to) || !self.bounds.contains(to) {
+            return false;
+        }
+        // No cutting corners between two blocked cells.
+        step.x == 0
+            || step.y == 0
+            || (!self.blocked.contains(&IVec2::new(to.x, from.y))
+                && !self.blocked.contains(&IVec2::new(from.x, to.y)))
+    }
+}
+
+// ============================================================================
+// Flow fields
+// ============================================================================
+
+/// Path costs toward one target region, for every cell that can reach it.
+pub struct FlowField {
+    costs: HashMap<IVec2, u32>,
+}
+
+impl FlowField {
+    /// Dijkstra outward from every open cell of `region`.
+    fn build(grid: &NavGrid, region: IVec2) -> Self {
+        let mut costs = HashMap::new();
+        let mut open = BinaryHeap::new();
+
+        let first_cell = region * REGION_CELLS;
+        for x in 0..REGION_CELLS {
+            for y in 0..REGION_CELLS {
+                let cell = first_cell + IVec2::new(x, y);
+                if !grid.blocked.contains(&cell) && grid.bounds.contains(cell) {
+                    costs.insert(cell, 0);
+                    open.push(Reverse((0, cell.x, cell.y)));
+                }
+            }
+        }
+
+        while let Some(Reverse((cost, x, y))) = open.pop() {
+            let cell = IVec2::new(x, y);
+            if costs.get(&cell).is_some_and(|&best| best < cost) {
+                continue;
+            }
+            for step in NEIGHBORS {
+                if !grid.can_step(cell, step) {
+                    continue;
+                }
+                let step_cost = if step.x == 0 || step.y == 0 {
+                    STRAIGHT_COST
+                } else {
+                    DIAGONAL_COST
+                };
+                let next = cell + step;
+                let next_cost = cost + step_cost;
+                if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                    costs.insert(next, next_cost);
+                    open.push(Reverse((next_cost, next.x, next.y)));
+                }
+            }
+        }
+
+        Self { costs }
+    }
+
+    /// The cheapest cell next to `cell`, or `None` if `cell` can't reach the region.
+    fn next_cell(&self, grid: &NavGrid, cell: IVec2) -> Option<IVec2> {
+        let own_cost = *self.costs.get(&cell)?;
+        NEIGHBORS
+            .into_iter()
+            .filter(|&step| grid.can_step(cell, step))
+            .filter_map(|step| {
+                let next = cell + step;
+                Some((next, *self.costs.get(&next)?))
+            })
+            .filter(|&(_, cost)| cost < own_cost)
+            .min_by_key(|&(next, cost)| (cost, next.x, next.y))
+            .map(|(next, _)| next)
+    }
+}
+
+/// Flow fields by faction and target region, built on demand and dropped when the level changes.
+#[derive(Resource, Default)]
+pub struct FlowFields {
+    fields: HashMap<(Faction, IVec2), FlowField>,
+}
+
+impl FlowFields {
+    fn region_of(grid: &NavGrid, pos: Vec2) -> IVec2 {
+        grid.cell_of(pos).div_euclid(IVec2::splat(REGION_CELLS))
+    }
+
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Makes sure a unit of `faction` at `from` can look up its way to `goal`.
+    pub fn request(&mut self, grid: &NavGrid, faction: Faction, from: Vec2, goal: Vec2) {
+        if grid.is_clear_line(from, goal) {
+            return;
+        }
+        let key = (faction, Self::region_of(grid, goal));
+        if self.fields.contains_key(&key) {
+            return;
+        }
+        if self.fields.len() >= MAX_FLOW_FIELDS {
+            self.fields.clear();
+        }
+        self.fields.insert(key, FlowField::build(grid, key.1));
+    }
+
+    /// Where a unit at `from` should head next on its way to `goal`:
+    /// `goal` itself when the way is clear, otherwise the center of the next cell of the field.
+    pub fn waypoint(&self, grid: &NavGrid, faction: Faction, from: Vec2, goal: Vec2) -> Vec2 {
+        if grid.is_clear_line(from, goal) {
+            return goal;
+        }
+        self.fields
+            .get(&(faction, Self::region_of(grid, goal)))
+            .and_then(|field| field.next_cell(grid, grid.cell_of(from)))
+            .map_or(goal, |next| grid.cell_center(next))
+    }
+}
+
+/// Fields lead through the old level's walls once the grid changes.
+fn clear_flow_fields(mut flow_fields: ResMut<FlowFields>) {
+    flow_fields.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A level 20 cells square with a wall at x = 0 from y = -5 to 5 cells.
+    fn walled_grid() -> NavGrid {
+        let area = Rect::new(-10.0, -10.0, 10.0, 10.0);
+        let wall = (-5..=5).map(|y| Vec2::new(0.5, y as f32 + 0.5));
+        let mut grid = NavGrid::default();
+        grid.rebuild(
+            Rect::from_corners(area.min * NAV_CELL_SIZE, area.max * NAV_CELL_SIZE),
+            wall.map(|cell| cell * NAV_CELL_SIZE),
+        );
+        grid
+    }
+
+    #[test]
+    fn units_walk_around_a_wall() {
+        let grid = walled_grid();
+        let mut fields = FlowFields::default();
+        let goal = Vec2::new(6.5, 0.5) * NAV_CELL_SIZE;
+        let mut pos = Vec2::new(-6.5, 0.5) * NAV_CELL_SIZE;
+        assert!(!grid.is_clear_line(pos, goal));
+
+        fields.request(&grid, Faction::Player, pos, goal);
+        for _ in 0..64 {
+            if grid.is_clear_line(pos, goal) {
+                break;
+            }
+            let next = fields.waypoint(&grid, Faction::Player, pos, goal);
+            assert!(!grid.is_blocked(next));
+            pos = next;
+        }
+
+        assert!(grid.is_clear_line(pos, goal));
+    }
+
+    #[test]
+    fn moves_slide_along_walls() {
+        let grid = walled_grid();
+        let from = Vec2::new(-0.2, 0.5) * NAV_CELL_SIZE;
+
+        let delta = grid.constrain(from, Vec2::new(30.0, 10.0));
+
+        assert_eq!(delta, Vec2::new(0.0, 10.0));
+    }
+}
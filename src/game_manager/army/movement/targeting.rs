@@ -0,0 +1,184 @@
+//! Targeting policies: how a unit picks among the enemies around it.
+//!
+//! Policies only run in the full grid search of `target_finding_system`, so they share its
+//! time slicing; units in melee reach or focusing a squad keep picking the nearest enemy.
+//! [`Support`] units look for wounded allies instead.
+
+use crate::prelude::*;
+
+use super::{TargetSearchFrame, UnitSpatialGrid, nearest_candidates};
+
+/// How far a [`TargetPolicy::Counter`] unit looks for the kind it counters.
+const COUNTER_SEARCH_RADIUS: f32 = 600.0;
+
+/// How far a [`TargetPolicy::Backline`] unit looks past the front line.
+const BACKLINE_SEARCH_RADIUS: f32 = 900.0;
+
+/// Range a backline target has to outreach a nearer one by per pixel further away.
+const BACKLINE_DISTANCE_WEIGHT: f32 = 0.5;
+
+/// Distance at which a [`TargetPolicy::Threat`] target counts half as threatening.
+const THREAT_FALLOFF: f32 = 150.0;
+
+/// How far a support unit looks for allies to heal.
+const SUPPORT_SEARCH_RADIUS: f32 = 600.0;
+
+/// How a unit picks its next target, from the `targeting` column.
+#[derive(serde::Deserialize, Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum TargetPolicy {
+    /// One of the nearest enemies, spread out so units don't all pile onto one.
+    #[default]
+    Nearest,
+    /// The nearest enemy of the kind this unit counters, if one is close enough.
+    Counter,
+    /// The most wounded of the nearest enemies.
+    LowestHp,
+    /// The longest-ranged enemy within reach, past whatever stands in front of it.
+    Backline,
+    /// The nearest enemy that deals the most damage per second.
+    Threat,
+}
+
+impl TargetPolicy {
+    /// How far the policy searches; `None` looks at the nearest enemies only.
+    fn search_radius(self) -> Option<f32> {
+        match self {
+            Self::Counter => Some(COUNTER_SEARCH_RADIUS),
+            Self::Backline => Some(BACKLINE_SEARCH_RADIUS),
+            Self::Nearest | Self::LowestHp | Self::Threat => None,
+        }
+    }
+
+    /// How much `me` wants to attack a candidate `dist` pixels away with `hp` health left;
+    /// higher is better and `None` rules it out.
+    fn score(
+        self,
+        me: &CombatAttributes,
+        candidate: &CombatAttributes,
+        hp: f32,
+        dist: f32,
+    ) -> Option<f32> {
+        match self {
+            Self::Nearest => Some(-dist),
+            Self::Counter => me
+                .counter
+                .as_ref()
+                .filter(|counter| **counter == candidate.unity_kind)
+                .map(|_| -dist),
+            Self::LowestHp => Some(-hp),
+            Self::Backline => Some(candidate.attack_range - dist * BACKLINE_DISTANCE_WEIGHT),
+            Self::Threat => {
+                let dps = candidate.damage / candidate.attack_speed.max(0.1);
+                Some(dps / (1.0 + dist / THREAT_FALLOFF))
+            }
+        }
+    }
+}
+
+/// The best target for `policy` around `my_pos`, or `None` if nothing suits it.
+pub(super) fn find_policy_target(
+    policy: TargetPolicy,
+    self_entity: Entity,
+    my_pos: Vec2,
+    my_faction: Faction,
+    my_stats: &CombatAttributes,
+    grid: &UnitSpatialGrid,
+    q_candidates: &Query<(&Health, &CombatAttributes)>,
+) -> Option<Entity> {
+    let candidates = match policy.search_radius() {
+        Some(radius) => {
+            let mut enemies = Vec::new();
+            grid.for_each_within(my_pos, radius, |neighbor, dist| {
+                if neighbor.faction != my_faction {
+                    enemies.push((*neighbor, dist * dist));
+                }
+            });
+            enemies
+        }
+        None => nearest_candidates(self_entity, my_pos, my_faction, grid),
+    };
+
+    candidates
+        .into_iter()
+        .filter_map(|(candidate, dist_sq)| {
+            let (health, stats) = q_candidates.get(candidate.entity).ok()?;
+            let score = policy.score(my_stats, stats, health.get_current(), dist_sq.sqrt())?;
+            Some((candidate, score))
+        })
+        // Equal scores go to the lowest id
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.id.cmp(&a.0.id)))
+        .map(|(candidate, _)| candidate.entity)
+}
+
+/// Points support units at the most wounded ally nearby, or the nearest ally to keep up
+/// with while nobody needs them. Like enemy searches, these are spread across frames.
+pub(super) fn support_target_finding_system(
+    grid: Res<UnitSpatialGrid>,
+    frame: Res<TargetSearchFrame>,
+    mut q_supports: Query<(Entity, &UnitId, &Transform, &mut Target, &Faction), With<Support>>,
+    q_health: Query<&Health>,
+) {
+    for (entity, id, transform, mut target, faction) in &mut q_supports {
+        let tending = target
+            .0
+            .and_then(|ally| q_health.get(ally).ok())
+            .is_some_and(Health::is_injured);
+        if tending {
+            continue;
+        }
+        if !frame.is_turn_of(*id) {
+            continue;
+        }
+
+        let my_pos = transform.translation.truncate();
+        let mut wounded: Option<(Entity, f32)> = None;
+        let mut nearest: Option<(Entity, f32)> = None;
+        grid.for_each_within(my_pos, SUPPORT_SEARCH_RADIUS, |neighbor, dist| {
+            if neighbor.entity == entity || neighbor.faction != *faction {
+                return;
+            }
+            if nearest.is_none_or(|(_, best)| dist < best) {
+                nearest = Some((neighbor.entity, dist));
+            }
+            let Ok(health) = q_health.get(neighbor.entity) else {
+                return;
+            };
+            let missing = health.get_max() - health.get_current();
+            if missing > 0.0 && wounded.is_none_or(|(_, most)| missing > most) {
+                wounded = Some((neighbor.entity, missing));
+            }
+        });
+        target.0 = wounded.or(nearest).map(|(ally, _)| ally);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_rank_the_same_candidates_differently() {
+        let mut cavalry = CombatAttributes::melee(UnitKind::new("Cavalry"));
+        cavalry.counter = Some(UnitKind::new("Archer"));
+        let shield = CombatAttributes::melee(UnitKind::new("Shield"));
+        let mut archer = CombatAttributes::ranged(UnitKind::new("Archer"));
+        archer.attack_range = 500.0;
+
+        // A healthy shield up close, a wounded archer far behind it
+        let best = |policy: TargetPolicy| {
+            let near = policy.score(&cavalry, &shield, 90.0, 50.0);
+            let far = policy.score(&cavalry, &archer, 10.0, 400.0);
+            match (near, far) {
+                (Some(near), Some(far)) if far > near => "archer",
+                (Some(_), _) => "shield",
+                (None, Some(_)) => "archer",
+                (None, None) => "none",
+            }
+        };
+
+        assert_eq!(best(TargetPolicy::Nearest), "shield");
+        assert_eq!(best(TargetPolicy::Counter), "archer");
+        assert_eq!(best(TargetPolicy::LowestHp), "archer");
+        assert_eq!(best(TargetPolicy::Backline), "archer");
+    }
+}
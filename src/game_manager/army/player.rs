@@ -2,7 +2,11 @@ use crate::prelude::*;
 pub(crate) fn plugin(_app: &mut bevy::app::App) {}
 
 #[derive(Component, Default, Reflect)]
-#[require(RequireShadowSprite, DespawnOnExit::<GameState>(GameState::Battle))]
+#[require(
+    RequireShadowSprite,
+    StatModifiers,
+    DespawnOnExit::<GameState>(GameState::Battle)
+)]
 pub struct Unit;
 
 #[derive(Component, Default, Reflect)]
@@ -12,7 +16,9 @@ pub struct PlayerFaction;
 #[derive(Component, Reflect)]
 pub struct OriginalColor(pub Color);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Component, serde::Serialize, serde::Deserialize,
+)]
 pub enum Faction {
     Player,
     Enemy,
@@ -26,7 +26,20 @@ pub struct EnemySquadBundle {
     enemy_squad: EnemySquad,
 }
 
+/// Reads the squad from LDtk; the optional `formation` and `stance` fields name a
+/// [`Formation`] and a [`Stance`]. LDtk can't name a squad to focus, so `Focus` acts as `Advance`.
 pub fn squad_from_field(entity_instance: &EntityInstance) -> Squad {
+    let formation = entity_instance
+        .get_string_field("formation")
+        .ok()
+        .and_then(|name| Formation::from_name(name))
+        .unwrap_or_default();
+    let stance = entity_instance
+        .get_string_field("stance")
+        .ok()
+        .and_then(|name| Stance::from_name(name))
+        .unwrap_or_default();
+
     Squad::new(
         entity_instance
             .get_string_field("unit_prefab")
@@ -37,4 +50,6 @@ pub fn squad_from_field(entity_instance: &EntityInstance) -> Squad {
             .expect("expected entity to have non-nullable unit_count int field")
             .clone()) as usize,
     )
+    .with_formation(formation)
+    .with_stance(stance)
 }
@@ -0,0 +1,243 @@
+use std::f32::consts::TAU;
+
+use bevy::picking::prelude::*;
+
+use crate::prelude::*;
+
+const UNIT_SIZE: f32 = 100.0;
+const UNIT_SPACING: f32 = 10.0;
+/// Distance between neighbouring unit slots.
+const STEP: f32 = UNIT_SIZE + UNIT_SPACING;
+
+/// Ranks a column is deep.
+const COLUMN_DEPTH: usize = 10;
+/// Units in each rank of a line.
+const LINE_WIDTH: usize = 25;
+/// Units in each rank of a skirmish screen.
+const SKIRMISH_WIDTH: usize = 10;
+/// How much further apart skirmishers stand than a close-order formation.
+const SKIRMISH_SPREAD: f32 = 1.8;
+/// Largest random-looking nudge of a skirmisher, as a share of its spacing.
+const SKIRMISH_JITTER: f32 = 0.3;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        pick_formation_while_dragging.run_if(in_state(GameState::Preparing)),
+    );
+    app.add_observer(cycle_formation_on_right_click);
+}
+
+/// How a squad lays out its units around the squad center.
+///
+/// Layouts are described facing the enemy: player squads face right, enemy squads left.
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect,
+)]
+pub enum Formation {
+    /// A deep block, ten ranks from front to back.
+    #[default]
+    Column,
+    /// A wide, shallow front.
+    Line,
+    /// A triangle with its tip toward the enemy.
+    Wedge,
+    /// Rings around the squad center.
+    Circle,
+    /// A loose, staggered screen.
+    Skirmish,
+}
+
+impl Formation {
+    pub const ALL: [Self; 5] = [
+        Formation::Column,
+        Formation::Line,
+        Formation::Wedge,
+        Formation::Circle,
+        Formation::Skirmish,
+    ];
+
+    /// The formation after this one, wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&formation| formation == self);
+        Self::ALL[(index.unwrap_or(0) + 1) % Self::ALL.len()]
+    }
+
+    /// Parses a formation name as written in LDtk, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|formation| format!("{formation:?}").eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Local offset of the unit at `index` in a squad of `total_units`, centered on the squad.
+    pub fn offset(self, index: usize, total_units: usize, faction: Faction) -> Vec2 {
+        let offset = match self {
+            Formation::Column => ranks_offset(index, total_units, COLUMN_DEPTH, STEP),
+            Formation::Line => ranks_offset(index, total_units, LINE_WIDTH, STEP).perp(),
+            Formation::Wedge => wedge_offset(index, total_units),
+            Formation::Circle => circle_offset(index, total_units),
+            Formation::Skirmish => skirmish_offset(index, total_units),
+        };
+
+        match faction {
+            Faction::Player => offset,
+            Faction::Enemy => Vec2::new(-offset.x, offset.y),
+        }
+    }
+}
+
+/// Offset in a block `width` units across `x`, filled row by row along `y`.
+fn ranks_offset(index: usize, total_units: usize, width: usize, step: f32) -> Vec2 {
+    let ranks = total_units.div_ceil(width);
+    let x = index % width;
+    let y = index / width;
+
+    Vec2::new(
+        (x as f32 - (width as f32 - 1.0) / 2.0) * step,
+        (y as f32 - (ranks as f32 - 1.0) / 2.0) * step,
+    )
+}
+
+/// Row `k` of the wedge holds `2k + 1` units, so the tip leads the charge.
+fn wedge_offset(index: usize, total_units: usize) -> Vec2 {
+    let row_of = |index: usize| (index as f32).sqrt() as usize;
+    let row = row_of(index);
+    let rows = row_of(total_units.saturating_sub(1)) + 1;
+    let slot = index - row * row;
+
+    Vec2::new(
+        ((rows as f32 - 1.0) / 2.0 - row as f32) * STEP,
+        (slot as f32 - row as f32) * STEP,
+    )
+}
+
+/// Fills rings from the center out, each holding as many units as fit around it.
+fn circle_offset(index: usize, total_units: usize) -> Vec2 {
+    if index == 0 {
+        return Vec2::ZERO;
+    }
+
+    let mut first = 1;
+    let mut ring = 1;
+    loop {
+        let capacity = (TAU * ring as f32).floor() as usize;
+        // The outermost ring spreads whatever is left evenly around it.
+        let count = capacity.min(total_units.saturating_sub(first));
+        if count == 0 {
+            return Vec2::ZERO;
+        }
+        if index < first + count {
+            let angle = TAU * (index - first) as f32 / count as f32;
+            return Vec2::from_angle(angle) * ring as f32 * STEP;
+        }
+        first += count;
+        ring += 1;
+    }
+}
+
+/// A widely spaced grid with alternate ranks staggered and every unit nudged a little.
+fn skirmish_offset(index: usize, total_units: usize) -> Vec2 {
+    let step = STEP * SKIRMISH_SPREAD;
+    let slot = ranks_offset(index, total_units, SKIRMISH_WIDTH, step);
+    let stagger = if (index / SKIRMISH_WIDTH) % 2 == 1 {
+        step / 2.0
+    } else {
+        0.0
+    };
+
+    // A fixed hash rather than the battle RNG, so layouts are the same every time.
+    let hash = (index as u32).wrapping_mul(2_654_435_761);
+    let jitter = |bits: u32| ((bits & 0xff) as f32 / 255.0 - 0.5) * 2.0 * SKIRMISH_JITTER * step;
+
+    // Same orientation as a line: rows run across the front, the first row leads.
+    Vec2::new(
+        -slot.y + jitter(hash >> 8),
+        slot.x + stagger + jitter(hash >> 16),
+    )
+}
+
+/// Moves a squad's units into the slots of its current formation.
+fn relayout_squad(
+    squad: &Squad,
+    faction: Faction,
+    units: &RootStationSquad,
+    q_transform: &mut Query<&mut Transform, With<BelongToSquad>>,
+) {
+    for (index, &unit) in units.iter().enumerate() {
+        let Ok(mut transform) = q_transform.get_mut(unit) else {
+            continue;
+        };
+        let offset = squad.formation.offset(index, squad.max_unit_count, faction);
+        transform.translation.x = offset.x;
+        transform.translation.y = offset.y;
+    }
+}
+
+// ============================================================================
+// Choosing a formation
+// ============================================================================
+
+/// Right-clicking a player squad while preparing switches it to the next formation.
+fn cycle_formation_on_right_click(
+    click: On<Pointer<Click>>,
+    game_state: Option<Res<State<GameState>>>,
+    q_main_mesh: Query<&BelongTo, With<MainMesh>>,
+    q_unit_belong_to: Query<&BelongToSquad, With<PlayerFaction>>,
+    mut q_squad: Query<(&mut Squad, &Faction, &RootStationSquad)>,
+    mut q_transform: Query<&mut Transform, With<BelongToSquad>>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
+) {
+    if click.button != PointerButton::Secondary {
+        return;
+    }
+    if game_state.is_none_or(|state| *state.get() != GameState::Preparing) {
+        return;
+    }
+    let Ok(belong_to_unit) = q_main_mesh.get(click.entity) else {
+        return;
+    };
+    let Ok(belong_to_squad) = q_unit_belong_to.get(belong_to_unit.0) else {
+        return;
+    };
+    let Ok((mut squad, faction, units)) = q_squad.get_mut(belong_to_squad.0) else {
+        return;
+    };
+
+    squad.formation = squad.formation.next();
+    relayout_squad(&squad, *faction, units, &mut q_transform);
+    placement_msg.write(PlacementChangedMessage);
+    info!(
+        "Squad {:?} formation: {:?}",
+        belong_to_squad.0, squad.formation
+    );
+}
+
+/// Number keys 1-5 pick a formation for the squad being dragged.
+fn pick_formation_while_dragging(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut q_select_squad: Query<(&mut Squad, &Faction, &RootStationSquad), With<SelectSquad>>,
+    mut q_transform: Query<&mut Transform, With<BelongToSquad>>,
+) {
+    const KEYS: [KeyCode; 5] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+    ];
+    let Some(formation) = KEYS
+        .iter()
+        .position(|&key| keyboard.just_pressed(key))
+        .map(|index| Formation::ALL[index])
+    else {
+        return;
+    };
+
+    for (mut squad, faction, units) in &mut q_select_squad {
+        if squad.formation != formation {
+            squad.formation = formation;
+            relayout_squad(&squad, *faction, units, &mut q_transform);
+        }
+    }
+}
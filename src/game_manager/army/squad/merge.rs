@@ -0,0 +1,148 @@
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(merge_squads);
+}
+
+/// How close a dragged squad has to be dropped to another one to merge into it.
+pub(crate) const MERGE_DISTANCE: f32 = 120.0;
+
+/// Moves the units of `from` into `into`, a squad of the same unit with room for them.
+/// Whatever doesn't fit stays behind in `from`.
+#[derive(Event)]
+pub struct MergeSquads {
+    pub from: Entity,
+    pub into: Entity,
+}
+
+/// Whether `from` can give any of its units to `into`.
+pub(crate) fn can_merge(from: &Squad, into: &Squad) -> bool {
+    from.child_prefab_name == into.child_prefab_name
+        && from.current_unit_count > 0
+        && into.current_unit_count < into.max_unit_count
+}
+
+fn merge_squads(
+    trigger: On<MergeSquads>,
+    mut commands: Commands,
+    unit_stats: Res<UnitStatsCache>,
+    memory_stats: Res<MemoryStatsCache>,
+    mut player_gold: ResMut<PlayerGold>,
+    mut q_squads: Query<
+        (
+            &mut Squad,
+            &mut SquadLossTracker,
+            &mut Veterancy,
+            &Faction,
+            &RootStationSquad,
+            Has<BigEyeBuff>,
+            Has<GoldenHeartBuff>,
+        ),
+        With<PlayerSquad>,
+    >,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
+) {
+    let Ok([from, into]) = q_squads.get_many_mut([trigger.from, trigger.into]) else {
+        return;
+    };
+    let (mut from_squad, mut from_tracker, from_veterancy, _, from_units, big_eye, golden_heart) =
+        from;
+    let (
+        mut into_squad,
+        mut into_tracker,
+        mut into_veterancy,
+        faction,
+        _,
+        into_big_eye,
+        into_golden_heart,
+    ) = into;
+    if !can_merge(&from_squad, &into_squad) {
+        return;
+    }
+    let Some(definition) = unit_stats.stats.get(&into_squad.child_prefab_name) else {
+        return;
+    };
+
+    let moved = from_squad
+        .current_unit_count
+        .min(into_squad.max_unit_count - into_squad.current_unit_count);
+    let slots = into_squad.current_unit_count..into_squad.current_unit_count + moved;
+    let units = spawn_units_in_formation(
+        &mut commands,
+        slots,
+        &into_squad,
+        definition,
+        *faction,
+        trigger.into,
+    );
+    commands.entity(trigger.into).add_children(&units);
+    into_squad.current_unit_count += moved;
+    *into_tracker = SquadLossTracker::for_losses(into_squad.loss_percentage());
+
+    // The merged squad fights on as the more seasoned of the two
+    if from_veterancy.xp() > into_veterancy.xp() {
+        let rank = into_veterancy.rank();
+        *into_veterancy = *from_veterancy;
+        if into_veterancy.rank() > rank {
+            commands.trigger(PromotedEvent {
+                squad: trigger.into,
+                rank: into_veterancy.rank(),
+            });
+        }
+    }
+
+    if moved == from_squad.current_unit_count {
+        // Fully absorbed: its memories go along with its units. A memory the other squad
+        // already has can't be carried twice, so it is paid back in full.
+        let held = MemoryKind::from_buffs(into_big_eye, into_golden_heart);
+        let mut into_commands = commands.entity(trigger.into);
+        for memory in MemoryKind::from_buffs(big_eye, golden_heart) {
+            if !held.contains(&memory) {
+                memory.insert_buff(&mut into_commands);
+                continue;
+            }
+            let refund = memory_stats
+                .stats
+                .get(memory.id())
+                .map_or(0, |row| row.price.max(0) as u32);
+            player_gold.amount = player_gold.amount.saturating_add(refund);
+            info!("Refunded {refund} gold for a duplicate {memory:?} memory");
+        }
+        commands.entity(trigger.from).despawn();
+    } else {
+        for &unit in from_units.iter().rev().take(moved) {
+            commands.entity(unit).despawn();
+        }
+        from_squad.current_unit_count -= moved;
+        *from_tracker = SquadLossTracker::for_losses(from_squad.loss_percentage());
+    }
+
+    info!(
+        "Merged {} {} units from squad {:?} into {:?} ({}/{})",
+        moved,
+        into_squad.child_prefab_name,
+        trigger.from,
+        trigger.into,
+        into_squad.current_unit_count,
+        into_squad.max_unit_count
+    );
+    commands.trigger(SFXEvent::ui("put"));
+    placement_msg.write(PlacementChangedMessage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_squads_of_the_same_unit_with_room_merge() {
+        let damaged = Squad::new("Spear".to_string(), 50).with_survivors(20);
+        let full = Squad::new("Spear".to_string(), 50).with_survivors(50);
+        let archers = Squad::new("Archer".to_string(), 50).with_survivors(20);
+
+        assert!(can_merge(&damaged, &damaged));
+        assert!(can_merge(&full, &damaged));
+        assert!(!can_merge(&damaged, &full));
+        assert!(!can_merge(&damaged, &archers));
+    }
+}
@@ -3,7 +3,23 @@ use crate::prelude::*;
 mod squad;
 pub(crate) use squad::*;
 
+mod formation;
+pub(crate) use formation::Formation;
+
+mod stance;
+pub(crate) use stance::Stance;
+
 mod spawn;
+pub(crate) use spawn::{FormationSlot, spawn_units_in_formation};
+
+mod reinforce;
+pub(crate) use reinforce::{ReinforceSquad, Reinforcement};
+
+mod merge;
+pub(crate) use merge::*;
+
+mod veterancy;
+pub(crate) use veterancy::*;
 
 mod move_squad;
 pub(crate) use move_squad::SelectSquad;
@@ -15,9 +31,20 @@ mod enemy_squad;
 pub(crate) use enemy_squad::*;
 
 pub(crate) fn plugin(app: &mut App) {
-    squad::plugin(app);
-    spawn::plugin(app);
+    headless_plugin(app);
+    formation::plugin(app);
+    stance::plugin(app);
     move_squad::plugin(app);
+    reinforce::plugin(app);
+    merge::plugin(app);
     player_squad::plugin(app);
     enemy_squad::plugin(app);
+    veterancy::plugin(app);
+}
+
+/// Squad bookkeeping that doesn't depend on prefabs, LDtk or input.
+pub(crate) fn headless_plugin(app: &mut App) {
+    squad::plugin(app);
+    spawn::plugin(app);
+    veterancy::headless_plugin(app);
 }
@@ -1,4 +1,5 @@
 use bevy::picking::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 use bevy_ecs_ldtk::app::LdtkEntityAppExt;
 
 use crate::prelude::*;
@@ -97,21 +98,55 @@ fn on_drag(
     transform.translation.y = world_pos.y;
 }
 
+/// Drops the dragged squad: on a [`SellZone`] it is sold, on a squad of the same unit
+/// with room it merges into it, outside the boundary it goes back, anywhere else it moves.
 fn on_drag_end(
     _drag_end: On<Pointer<DragEnd>>,
-    mut q_select_squad: Query<(Entity, &mut Transform, &SquadOriginalPosition), With<SelectSquad>>,
+    mut q_select_squad: Query<
+        (Entity, &Squad, &mut Transform, &SquadOriginalPosition),
+        With<SelectSquad>,
+    >,
+    q_other_squads: Query<(Entity, &Squad, &Transform), (With<PlayerSquad>, Without<SelectSquad>)>,
+    q_sell_zones: Query<&RelativeCursorPosition, With<SellZone>>,
     in_boundary: Res<InBoundary>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
     mut commands: Commands,
 ) {
-    let Ok((entity, mut transform, original_pos)) = q_select_squad.single_mut() else {
+    let Ok((entity, squad, mut transform, original_pos)) = q_select_squad.single_mut() else {
         return;
     };
 
-    if !in_boundary.0 {
+    if cursor_over_sell_zone(&q_sell_zones) {
+        commands
+            .entity(entity)
+            .remove::<(SelectSquad, SquadOriginalPosition)>();
+        commands.trigger(SellSquad { squad: entity });
+        return;
+    }
+
+    let drop_pos = transform.translation.truncate();
+    let merge_target = q_other_squads
+        .iter()
+        .filter(|(_, other, other_transform)| {
+            can_merge(squad, other)
+                && other_transform.translation.truncate().distance(drop_pos) <= MERGE_DISTANCE
+        })
+        .min_by(|(_, _, a), (_, _, b)| {
+            let a = a.translation.truncate().distance_squared(drop_pos);
+            let b = b.translation.truncate().distance_squared(drop_pos);
+            a.total_cmp(&b)
+        })
+        .map(|(other, ..)| other);
+
+    if !in_boundary.0 || merge_target.is_some() {
+        // Whatever doesn't fit into the merge target stays where it was
         transform.translation = original_pos.0;
         commands
             .entity(entity)
             .remove::<(SelectSquad, SquadOriginalPosition)>();
+        if let Some(into) = merge_target {
+            commands.trigger(MergeSquads { from: entity, into });
+        }
         return;
     }
 
@@ -122,4 +157,5 @@ fn on_drag_end(
         .insert((RequiredAnimation::Put, RunWithNoModel))
         .remove::<SquadOriginalPosition>();
     commands.entity(entity).remove::<SelectSquad>();
+    placement_msg.write(PlacementChangedMessage);
 }
@@ -10,19 +10,15 @@ pub struct PlayerSquad;
 pub struct SquadOriginPosition(pub Vec2);
 /// Spawns a PlayerSquad entity at the given world position.
 /// Units will be spawned automatically by the `spawn_units_for_new_squads` system.
-pub fn spawn_player_squad(
-    commands: &mut Commands,
-    prefab_name: &str,
-    position: Vec2,
-    unit_count: usize,
-) -> Entity {
+pub fn spawn_player_squad(commands: &mut Commands, squad: Squad, position: Vec2) -> Entity {
+    let name = Name::new(format!("{}_Squad", squad.child_prefab_name));
     commands
         .spawn((
             PlayerSquad,
-            Squad::new(prefab_name.to_string(), unit_count),
+            squad,
             RootStationSquad::default(),
             Transform::from_xyz(position.x, position.y, 0.0),
-            Name::new(format!("{}_Squad", prefab_name)),
+            name,
         ))
         .id()
 }
@@ -0,0 +1,150 @@
+use crate::game_manager::shop::{PlayerGold, PlayerGoldNotEnoughMessage};
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        reinforce_while_dragging.run_if(in_state(GameState::Preparing)),
+    );
+    app.add_observer(reinforce_squad);
+}
+
+/// Gold for `count` units of a squad whose full `max_units` cost `squad_cost`, rounded up.
+fn reinforcement_cost(squad_cost: u32, count: usize, max_units: usize) -> u32 {
+    if max_units == 0 {
+        return 0;
+    }
+    (squad_cost as usize * count).div_ceil(max_units) as u32
+}
+
+/// How many of `missing` units `gold` pays for.
+fn affordable_units(gold: u32, squad_cost: u32, missing: usize, max_units: usize) -> usize {
+    if squad_cost == 0 {
+        return missing;
+    }
+    (gold as usize * max_units / squad_cost as usize).min(missing)
+}
+
+/// Refilling a squad toward full strength with the gold at hand.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Reinforcement {
+    /// Units the gold pays for, never more than the squad is missing.
+    pub units: usize,
+    /// Gold those units cost.
+    pub cost: u32,
+    /// Gold it would take to refill the squad completely.
+    pub full_cost: u32,
+}
+
+impl Reinforcement {
+    pub(crate) fn offer(squad: &Squad, squad_cost: u32, gold: u32) -> Self {
+        let missing = squad
+            .max_unit_count
+            .saturating_sub(squad.current_unit_count);
+        let units = affordable_units(gold, squad_cost, missing, squad.max_unit_count);
+        Self {
+            units,
+            cost: reinforcement_cost(squad_cost, units, squad.max_unit_count),
+            full_cost: reinforcement_cost(squad_cost, missing, squad.max_unit_count),
+        }
+    }
+}
+
+/// Refills a placed player squad with as many of its missing units as the gold buys.
+#[derive(Event)]
+pub struct ReinforceSquad {
+    pub squad: Entity,
+}
+
+/// T reinforces the squad being dragged.
+fn reinforce_while_dragging(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    q_select_squad: Query<Entity, (With<SelectSquad>, With<PlayerSquad>)>,
+    mut commands: Commands,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+    for squad in &q_select_squad {
+        commands.trigger(ReinforceSquad { squad });
+    }
+}
+
+fn reinforce_squad(
+    trigger: On<ReinforceSquad>,
+    unit_stats: Res<UnitStatsCache>,
+    mut player_gold: ResMut<PlayerGold>,
+    mut q_squads: Query<(&mut Squad, &mut SquadLossTracker, &Faction), With<PlayerSquad>>,
+    mut not_enough_gold_msg: MessageWriter<PlayerGoldNotEnoughMessage>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
+    mut commands: Commands,
+) {
+    let squad_entity = trigger.squad;
+    let Ok((mut squad, mut tracker, faction)) = q_squads.get_mut(squad_entity) else {
+        return;
+    };
+    if squad.current_unit_count >= squad.max_unit_count {
+        return;
+    }
+    let Some(definition) = unit_stats.stats.get(&squad.child_prefab_name) else {
+        return;
+    };
+
+    let offer = Reinforcement::offer(&squad, definition.cost.max(0) as u32, player_gold.amount);
+    if offer.units == 0 {
+        commands.trigger(SFXEvent::ui("invalid"));
+        not_enough_gold_msg.write(PlayerGoldNotEnoughMessage);
+        return;
+    }
+    player_gold.amount = player_gold.amount.saturating_sub(offer.cost);
+
+    let slots = squad.current_unit_count..squad.current_unit_count + offer.units;
+    let units = spawn_units_in_formation(
+        &mut commands,
+        slots,
+        &squad,
+        definition,
+        *faction,
+        squad_entity,
+    );
+    commands.entity(squad_entity).add_children(&units);
+    squad.current_unit_count += offer.units;
+    *tracker = SquadLossTracker::for_losses(squad.loss_percentage());
+    commands.trigger(SFXEvent::ui("put"));
+    placement_msg.write(PlacementChangedMessage);
+
+    info!(
+        "Reinforced squad {:?} with {} units for {} gold ({}/{})",
+        squad_entity, offer.units, offer.cost, squad.current_unit_count, squad.max_unit_count
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinforcements_cost_their_share_of_the_squad() {
+        // A 50-unit squad costing 20 gold: 10 gold buys half of it back
+        assert_eq!(affordable_units(10, 20, 40, 50), 25);
+        assert_eq!(reinforcement_cost(20, 25, 50), 10);
+
+        // Never more than the squad is missing, and partial units round the price up
+        assert_eq!(affordable_units(100, 20, 5, 50), 5);
+        assert_eq!(reinforcement_cost(20, 1, 50), 1);
+    }
+
+    #[test]
+    fn offer_covers_what_the_gold_buys() {
+        let squad = Squad::new("Spear".to_string(), 50).with_survivors(10);
+        assert_eq!(
+            Reinforcement::offer(&squad, 20, 10),
+            Reinforcement {
+                units: 25,
+                cost: 10,
+                full_cost: 16,
+            }
+        );
+        assert_eq!(Reinforcement::offer(&squad, 20, 0).units, 0);
+    }
+}
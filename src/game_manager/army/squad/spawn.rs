@@ -1,27 +1,35 @@
-use crate::prelude::*;
+use std::ops::Range;
 
-const UNIT_SIZE: f32 = 100.0;
-const UNIT_SPACING: f32 = 10.0;
-const GRID_WIDTH: usize = 10;
+use crate::prelude::*;
 
 pub(crate) fn plugin(app: &mut App) {
-    app.add_systems(Update, (spawn_units_for_new_squads, initialize_squad_count));
+    app.add_systems(Update, spawn_units_for_new_squads);
 }
 
 /// System that watches for new Squad components and spawns their units
 fn spawn_units_for_new_squads(
     mut commands: Commands,
     new_squads: Query<(Entity, &Squad, &Faction), Added<Squad>>,
+    unit_stats: Res<UnitStatsCache>,
 ) {
     for (squad_entity, squad, faction) in new_squads.iter() {
-        if squad.max_unit_count == 0 {
+        let unit_count = squad.current_unit_count;
+        if unit_count == 0 {
             continue;
         }
+        let Some(definition) = unit_stats.stats.get(&squad.child_prefab_name) else {
+            warn!(
+                "No unit definition for {} squad {:?}",
+                squad.child_prefab_name, squad_entity
+            );
+            continue;
+        };
 
         let unit_entities = spawn_units_in_formation(
             &mut commands,
-            squad.max_unit_count,
-            &squad.child_prefab_name,
+            0..unit_count,
+            squad,
+            definition,
             *faction,
             squad_entity,
         );
@@ -29,39 +37,37 @@ fn spawn_units_for_new_squads(
 
         info!(
             "Spawned {} units for {:?} {} squad {:?}",
-            squad.max_unit_count, faction, squad.child_prefab_name, squad_entity
+            unit_count, faction, squad.child_prefab_name, squad_entity
         );
     }
 }
 
-/// Spawns units in a grid formation as child entities
-fn spawn_units_in_formation(
+/// The slot of its squad's formation a unit was spawned into.
+#[derive(Component, Debug, Clone, Copy)]
+pub(crate) struct FormationSlot(pub usize);
+
+/// Spawns the units filling `slots` of the squad's formation; the caller parents them.
+/// Slots are laid out for a full squad, so survivors and reinforcements keep its shape.
+pub(crate) fn spawn_units_in_formation(
     commands: &mut Commands,
-    total_units: usize,
-    prefab_name: &str,
+    slots: Range<usize>,
+    squad: &Squad,
+    definition: &UnitRow,
     faction: Faction,
     squad_entity: Entity,
 ) -> Vec<Entity> {
-    let step = UNIT_SIZE + UNIT_SPACING;
-    let rows = (total_units as f32 / GRID_WIDTH as f32).ceil() as usize;
-    let offset_x = -((GRID_WIDTH as f32 - 1.0) * step) / 2.0;
-    let offset_y = -((rows as f32 - 1.0) * step) / 2.0;
+    let mut entities = Vec::with_capacity(slots.len());
 
-    let mut entities = Vec::with_capacity(total_units);
+    for index in slots {
+        let offset = squad.formation.offset(index, squad.max_unit_count, faction);
 
-    for index in 0..total_units {
-        let col = index % GRID_WIDTH;
-        let row = index / GRID_WIDTH;
-
-        let x = offset_x + (col as f32 * step);
-        let y = offset_y + (row as f32 * step);
-
-        let mut entity_commands = commands.spawn_prefab(prefab_name);
-        entity_commands.insert((
+        let mut entity_commands = commands.spawn((
             BelongToSquad(squad_entity),
-            Transform::from_xyz(x, y, 0.0),
-            Name::new(format!("{}_{}", prefab_name, index)),
+            Transform::from_xyz(offset.x, offset.y, 0.0),
+            FormationSlot(index),
+            Name::new(format!("{}_{}", definition.id, index)),
         ));
+        insert_unit(&mut entity_commands, definition);
 
         match faction {
             Faction::Player => entity_commands.insert(PlayerFaction),
@@ -73,10 +79,3 @@ fn spawn_units_in_formation(
 
     entities
 }
-
-/// Initializes current_unit_count to match max_unit_count for newly created squads
-fn initialize_squad_count(mut q_new_squads: Query<&mut Squad, Added<Squad>>) {
-    for mut squad in &mut q_new_squads {
-        squad.current_unit_count = squad.max_unit_count;
-    }
-}
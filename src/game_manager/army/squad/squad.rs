@@ -6,30 +6,58 @@ pub(crate) fn plugin(app: &mut App) {
         Update,
         reduce_squad_count_on_unit_death.in_set(AttackSet::DeathRecord),
     );
-
-    // Example consumer - can be customized or moved to other modules
-    #[cfg(debug_assertions)]
-    app.add_systems(Update, example_squad_loss_listener);
 }
 
 /// Data component for squads - stores the unit type and count
 #[derive(Component, Default, Reflect)]
-#[require(Transform, Visibility, SquadHitCount, SquadTakeHitCount)]
+#[require(
+    Transform,
+    Visibility,
+    SquadHitCount,
+    SquadTakeHitCount,
+    SquadLossTracker,
+    SquadMorale,
+    Veterancy
+)]
 pub struct Squad {
     pub child_prefab_name: String,
     pub max_unit_count: usize,
     pub current_unit_count: usize,
+    pub formation: Formation,
+    pub stance: Stance,
+    /// The enemy squad a [`Stance::Focus`] squad goes for.
+    pub focus: Option<Entity>,
 }
 
 impl Squad {
+    /// A full squad of `max_unit_count` units.
     pub fn new(child_prefab_name: String, max_unit_count: usize) -> Self {
         Self {
             child_prefab_name,
-            current_unit_count: 0,
+            current_unit_count: max_unit_count,
             max_unit_count,
+            formation: Formation::default(),
+            stance: Stance::default(),
+            focus: None,
         }
     }
 
+    pub fn with_formation(mut self, formation: Formation) -> Self {
+        self.formation = formation;
+        self
+    }
+
+    pub fn with_stance(mut self, stance: Stance) -> Self {
+        self.stance = stance;
+        self
+    }
+
+    /// Starts the squad with only `count` of its units, the survivors of an earlier night.
+    pub fn with_survivors(mut self, count: usize) -> Self {
+        self.current_unit_count = count.min(self.max_unit_count);
+        self
+    }
+
     /// Returns the current loss percentage (0-100)
     pub fn loss_percentage(&self) -> u8 {
         if self.max_unit_count == 0 {
@@ -40,6 +68,21 @@ impl Squad {
     }
 }
 
+/// Orders squads the same way in a battle and in its replay, where their entities differ:
+/// player squads first, then by where they start and what they field.
+pub(crate) fn battle_order(
+    (a, a_faction, a_transform): (&Squad, &Faction, &GlobalTransform),
+    (b, b_faction, b_transform): (&Squad, &Faction, &GlobalTransform),
+) -> std::cmp::Ordering {
+    let a_pos = a_transform.translation();
+    let b_pos = b_transform.translation();
+    (*a_faction == Faction::Enemy)
+        .cmp(&(*b_faction == Faction::Enemy))
+        .then(a_pos.x.total_cmp(&b_pos.x))
+        .then(a_pos.y.total_cmp(&b_pos.y))
+        .then_with(|| a.child_prefab_name.cmp(&b.child_prefab_name))
+}
+
 /// Component to track which loss thresholds have been crossed
 #[derive(Component, Default, Reflect, Debug)]
 pub struct SquadLossTracker {
@@ -48,6 +91,17 @@ pub struct SquadLossTracker {
     pub threshold_90_crossed: bool,
 }
 
+impl SquadLossTracker {
+    /// A tracker with the thresholds `loss_percentage` is already past marked as crossed.
+    pub fn for_losses(loss_percentage: u8) -> Self {
+        Self {
+            threshold_30_crossed: loss_percentage >= 30,
+            threshold_60_crossed: loss_percentage >= 60,
+            threshold_90_crossed: loss_percentage >= 90,
+        }
+    }
+}
+
 /// Relationship component that links a unit to its squad
 #[derive(Component, Reflect, Debug)]
 #[relationship(relationship_target = RootStationSquad)]
@@ -158,32 +212,3 @@ fn reduce_squad_count_on_unit_death(
         }
     }
 }
-
-/// Example system showing how to consume SquadLossThresholdMessage
-/// This can be used to trigger audio, VFX, UI updates, or game logic
-#[cfg(debug_assertions)]
-fn example_squad_loss_listener(mut loss_messages: MessageReader<SquadLossThresholdMessage>) {
-    for msg in loss_messages.read() {
-        debug!(
-            "🚨 Squad {:?} has lost {}% of units! ({}/{} remaining)",
-            msg.squad_entity, msg.loss_percentage, msg.remaining_units, msg.max_units
-        );
-
-        // Example actions based on threshold:
-        match msg.loss_percentage {
-            30 => {
-                // Could trigger: warning sound, yellow UI indicator
-                debug!("  → Squad morale should decrease slightly");
-            }
-            60 => {
-                // Could trigger: alarm sound, orange UI indicator, retreat suggestion
-                debug!("  → Squad is critically weakened");
-            }
-            90 => {
-                // Could trigger: panic sound, red UI indicator, automatic retreat
-                debug!("  → Squad is nearly destroyed!");
-            }
-            _ => {}
-        }
-    }
-}
@@ -0,0 +1,151 @@
+use bevy::picking::prelude::*;
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<FocusPicker>();
+    app.add_systems(
+        Update,
+        pick_stance_while_dragging.run_if(in_state(GameState::Preparing)),
+    );
+    app.add_systems(OnExit(GameState::Preparing), cancel_focus_picker);
+    app.add_observer(focus_clicked_enemy_squad);
+}
+
+/// What a squad does once the battle starts, read by the targeting and movement systems.
+///
+/// "Left" and "right" are seen from the squad looking at the enemy.
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect,
+)]
+pub enum Stance {
+    /// Chase the nearest enemy.
+    #[default]
+    Advance,
+    /// Stay put and only fight enemies that come within reach.
+    HoldPosition,
+    /// Swing out to the left before closing in.
+    FlankLeft,
+    /// Swing out to the right before closing in.
+    FlankRight,
+    /// Go for the units of [`Squad::focus`], falling back to [`Stance::Advance`] once it is gone.
+    Focus,
+    /// Fall back to where the battle started and hold there.
+    Retreat,
+}
+
+impl Stance {
+    pub const ALL: [Self; 6] = [
+        Stance::Advance,
+        Stance::HoldPosition,
+        Stance::FlankLeft,
+        Stance::FlankRight,
+        Stance::Focus,
+        Stance::Retreat,
+    ];
+
+    /// Parses a stance name as written in LDtk, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|stance| format!("{stance:?}").eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Which side a flanking squad swings out to, as a unit vector in world space.
+    pub fn flank_side(self, faction: Faction) -> Option<Vec2> {
+        // Player squads face right, so their left is up; enemy squads face left.
+        let left = match faction {
+            Faction::Player => Vec2::Y,
+            Faction::Enemy => Vec2::NEG_Y,
+        };
+        match self {
+            Stance::FlankLeft => Some(left),
+            Stance::FlankRight => Some(-left),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Choosing a stance
+// ============================================================================
+
+/// The player squad waiting for the player to click the enemy squad it should focus.
+#[derive(Resource, Default)]
+struct FocusPicker(Option<Entity>);
+
+/// While dragging a squad: W advance, S hold, Q/E flank left/right, R retreat,
+/// F then click an enemy to focus it.
+fn pick_stance_while_dragging(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut focus_picker: ResMut<FocusPicker>,
+    mut q_select_squad: Query<(Entity, &mut Squad), With<SelectSquad>>,
+) {
+    const KEYS: [(KeyCode, Stance); 5] = [
+        (KeyCode::KeyW, Stance::Advance),
+        (KeyCode::KeyS, Stance::HoldPosition),
+        (KeyCode::KeyQ, Stance::FlankLeft),
+        (KeyCode::KeyE, Stance::FlankRight),
+        (KeyCode::KeyR, Stance::Retreat),
+    ];
+
+    for (squad_entity, mut squad) in &mut q_select_squad {
+        if keyboard.just_pressed(KeyCode::KeyF) {
+            focus_picker.0 = match focus_picker.0 {
+                Some(picking) if picking == squad_entity => None,
+                _ => Some(squad_entity),
+            };
+            continue;
+        }
+
+        let Some(&(_, stance)) = KEYS.iter().find(|(key, _)| keyboard.just_pressed(*key)) else {
+            continue;
+        };
+        if squad.stance != stance {
+            squad.stance = stance;
+            squad.focus = None;
+            info!("Squad {:?} stance: {:?}", squad_entity, stance);
+        }
+        if focus_picker.0 == Some(squad_entity) {
+            focus_picker.0 = None;
+        }
+    }
+}
+
+/// Clicking an enemy unit after pressing F points the waiting squad at that unit's squad.
+fn focus_clicked_enemy_squad(
+    click: On<Pointer<Click>>,
+    mut focus_picker: ResMut<FocusPicker>,
+    q_main_mesh: Query<&BelongTo, With<MainMesh>>,
+    q_unit_belong_to: Query<&BelongToSquad, With<EnemyFaction>>,
+    mut q_squad: Query<&mut Squad>,
+) {
+    if click.button != PointerButton::Primary {
+        return;
+    }
+    let Some(squad_entity) = focus_picker.0 else {
+        return;
+    };
+    let Ok(belong_to_unit) = q_main_mesh.get(click.entity) else {
+        return;
+    };
+    let Ok(belong_to_squad) = q_unit_belong_to.get(belong_to_unit.0) else {
+        return;
+    };
+    let Ok(mut squad) = q_squad.get_mut(squad_entity) else {
+        focus_picker.0 = None;
+        return;
+    };
+
+    squad.stance = Stance::Focus;
+    squad.focus = Some(belong_to_squad.0);
+    focus_picker.0 = None;
+    info!(
+        "Squad {:?} focuses squad {:?}",
+        squad_entity, belong_to_squad.0
+    );
+}
+
+fn cancel_focus_picker(mut focus_picker: ResMut<FocusPicker>) {
+    focus_picker.0 = None;
+}
@@ -0,0 +1,304 @@
+//! Squad veterancy: kills and damage earn a squad ranks that make all of its units better.
+//!
+//! [`Veterancy`] lives on the squad, so it carries over between nights with it.
+
+use std::fmt;
+
+use bevy_image_font::{ImageFontText, LetterSpacing, atlas_sprites::ImageFontSpriteText};
+
+use crate::prelude::*;
+
+/// Experience for every enemy unit a squad finishes off.
+const XP_PER_KILL: f32 = 1.0;
+
+/// Experience for every point of damage a squad deals.
+const XP_PER_DAMAGE: f32 = 0.01;
+
+/// Where a unit wears its insignia, above its head.
+const INSIGNIA_OFFSET: Vec3 = Vec3::new(0.0, 60.0, 10.0);
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(swap_insignia_on_promotion);
+    app.add_systems(Update, add_insignia_to_new_units);
+}
+
+/// Tracking and rank bonuses, which the headless simulation needs too.
+pub(crate) fn headless_plugin(app: &mut App) {
+    app.add_observer(on_promoted);
+    app.add_systems(
+        Update,
+        (
+            earn_veterancy.in_set(AttackSet::DeathRecord),
+            apply_rank_to_new_units,
+        ),
+    );
+}
+
+// ============================================================================
+// Ranks
+// ============================================================================
+
+/// How seasoned a squad is, from its [`Veterancy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+pub enum Rank {
+    #[default]
+    Recruit,
+    Regular,
+    Veteran,
+    Elite,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 4] = [Rank::Recruit, Rank::Regular, Rank::Veteran, Rank::Elite];
+
+    /// Experience a squad needs to reach the rank.
+    pub fn threshold(self) -> f32 {
+        match self {
+            Rank::Recruit => 0.0,
+            Rank::Regular => 30.0,
+            Rank::Veteran => 80.0,
+            Rank::Elite => 160.0,
+        }
+    }
+
+    pub fn next(self) -> Option<Rank> {
+        Rank::ALL.into_iter().find(|rank| *rank > self)
+    }
+
+    /// What the rank adds to every unit of the squad.
+    pub fn modifiers(self) -> Vec<StatModifier> {
+        let source = || ModifierSource::Veterancy;
+        match self {
+            Rank::Recruit => Vec::new(),
+            Rank::Regular => vec![StatModifier::percent(Stat::Damage, source(), 0.1)],
+            Rank::Veteran => vec![
+                StatModifier::percent(Stat::Damage, source(), 0.15),
+                StatModifier::flat(Stat::Defense, source(), 1.0),
+            ],
+            Rank::Elite => vec![
+                StatModifier::percent(Stat::Damage, source(), 0.25),
+                StatModifier::flat(Stat::Defense, source(), 2.0),
+                StatModifier::percent(Stat::AttackSpeed, source(), -0.1),
+            ],
+        }
+    }
+
+    /// The numeral units of the rank wear over their heads, if any.
+    fn insignia(self) -> Option<&'static str> {
+        match self {
+            Rank::Recruit => None,
+            Rank::Regular => Some("I"),
+            Rank::Veteran => Some("II"),
+            Rank::Elite => Some("III"),
+        }
+    }
+}
+
+/// Battle experience a squad has earned over the whole run.
+#[derive(
+    Component, serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Reflect,
+)]
+pub struct Veterancy {
+    pub kills: u32,
+    pub damage: f32,
+}
+
+impl Veterancy {
+    pub fn xp(&self) -> f32 {
+        self.kills as f32 * XP_PER_KILL + self.damage * XP_PER_DAMAGE
+    }
+
+    pub fn rank(&self) -> Rank {
+        let xp = self.xp();
+        Rank::ALL
+            .into_iter()
+            .rev()
+            .find(|rank| xp >= rank.threshold())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Veterancy {
+    /// e.g. `Veteran 95/160 XP, 42 kills`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rank = self.rank();
+        write!(f, "{rank:?} {:.0}", self.xp())?;
+        if let Some(next) = rank.next() {
+            write!(f, "/{:.0}", next.threshold())?;
+        }
+        write!(f, " XP, {} kills", self.kills)
+    }
+}
+
+/// A squad reached a new rank.
+#[derive(Event)]
+pub struct PromotedEvent {
+    pub squad: Entity,
+    pub rank: Rank,
+}
+
+/// The rank insignia floating over a unit.
+#[derive(Component)]
+struct Insignia;
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Credits squads for the damage their units deal and the enemies they finish off.
+fn earn_veterancy(
+    mut ev_dealt: MessageReader<DamageDealtMessage>,
+    q_belong: Query<&BelongToSquad>,
+    q_health: Query<&Health>,
+    mut q_veterancy: Query<&mut Veterancy>,
+    mut killed: Local<Vec<Entity>>,
+    mut commands: Commands,
+) {
+    killed.clear();
+    for ev in ev_dealt.read() {
+        let Some(squad) = ev
+            .attacker
+            .and_then(|attacker| q_belong.get(attacker).ok())
+            .map(|belong| belong.0)
+        else {
+            continue;
+        };
+        let Ok(mut veterancy) = q_veterancy.get_mut(squad) else {
+            continue;
+        };
+
+        let rank = veterancy.rank();
+        veterancy.damage += ev.amount;
+        // Of several blows landing on a unit the same frame, the first gets the kill
+        let dead = q_health
+            .get(ev.target)
+            .is_ok_and(|health| !health.is_alive());
+        if dead && !killed.contains(&ev.target) {
+            killed.push(ev.target);
+            veterancy.kills += 1;
+        }
+
+        let new_rank = veterancy.rank();
+        if new_rank > rank {
+            commands.trigger(PromotedEvent {
+                squad,
+                rank: new_rank,
+            });
+        }
+    }
+}
+
+/// Promoted squads' units take on their new rank's bonuses.
+fn on_promoted(
+    trigger: On<PromotedEvent>,
+    q_squads: Query<&RootStationSquad>,
+    mut q_modifiers: Query<&mut StatModifiers>,
+) {
+    info!("Squad {:?} promoted to {:?}", trigger.squad, trigger.rank);
+    let Ok(units) = q_squads.get(trigger.squad) else {
+        return;
+    };
+    for &unit in units.iter() {
+        if let Ok(mut modifiers) = q_modifiers.get_mut(unit) {
+            modifiers.replace_source(ModifierSource::Veterancy, trigger.rank.modifiers());
+        }
+    }
+}
+
+/// Units joining a ranked squad, carried over or as reinforcements, share its rank.
+fn apply_rank_to_new_units(
+    mut q_units: Query<(&BelongToSquad, &mut StatModifiers), Added<BelongToSquad>>,
+    q_veterancy: Query<&Veterancy>,
+) {
+    for (belong, mut modifiers) in &mut q_units {
+        let Ok(veterancy) = q_veterancy.get(belong.0) else {
+            continue;
+        };
+        let rank = veterancy.rank();
+        if rank != Rank::Recruit {
+            modifiers.replace_source(ModifierSource::Veterancy, rank.modifiers());
+        }
+    }
+}
+
+/// Promoted squads' units swap their insignia for the new rank's.
+fn swap_insignia_on_promotion(
+    trigger: On<PromotedEvent>,
+    q_squads: Query<&RootStationSquad>,
+    q_insignia: Query<(Entity, &ChildOf), With<Insignia>>,
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    palette: Res<ColorPalette>,
+) {
+    let Ok(units) = q_squads.get(trigger.squad) else {
+        return;
+    };
+    for (insignia, child_of) in &q_insignia {
+        if units.contains(&child_of.parent()) {
+            commands.entity(insignia).despawn();
+        }
+    }
+    for &unit in units.iter() {
+        spawn_insignia(&mut commands, &assets, &palette, unit, trigger.rank);
+    }
+}
+
+/// Units joining a ranked squad wear its insignia too.
+fn add_insignia_to_new_units(
+    q_units: Query<(Entity, &BelongToSquad), Added<BelongToSquad>>,
+    q_veterancy: Query<&Veterancy>,
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    palette: Res<ColorPalette>,
+) {
+    for (unit, belong) in &q_units {
+        if let Ok(veterancy) = q_veterancy.get(belong.0) {
+            spawn_insignia(&mut commands, &assets, &palette, unit, veterancy.rank());
+        }
+    }
+}
+
+fn spawn_insignia(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    palette: &ColorPalette,
+    unit: Entity,
+    rank: Rank,
+) {
+    let Some(insignia) = rank.insignia() else {
+        return;
+    };
+    commands.spawn((
+        Name::new("Insignia"),
+        Insignia,
+        ImageFontSpriteText::default()
+            .color(palette.tan_light)
+            .letter_spacing(LetterSpacing::Pixel(2)),
+        ImageFontText::default()
+            .text(insignia)
+            .font(assets.load("image_font/example_variable_width_font.image_font.ron"))
+            .font_height(24.0),
+        Transform::from_translation(INSIGNIA_OFFSET),
+        ChildOf(unit),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_and_damage_earn_ranks() {
+        let mut veterancy = Veterancy::default();
+        assert_eq!(veterancy.rank(), Rank::Recruit);
+
+        veterancy.kills = 20;
+        veterancy.damage = 1000.0;
+        assert_eq!(veterancy.rank(), Rank::Regular);
+        assert_eq!(veterancy.to_string(), "Regular 30/80 XP, 20 kills");
+
+        veterancy.kills = 200;
+        assert_eq!(veterancy.rank(), Rank::Elite);
+        assert_eq!(Rank::Elite.next(), None);
+    }
+}
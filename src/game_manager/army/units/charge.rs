@@ -0,0 +1,152 @@
+use crate::prelude::*;
+
+/// Pixels a unit has to run at speed before its first hit counts as a charge.
+const CHARGE_RUN_UP: f32 = 150.0;
+
+/// Share of its own top speed a unit has to keep up for the run-up to count.
+const CHARGE_MIN_SPEED_SHARE: f32 = 0.6;
+
+/// Knockback of a full-speed charge by a weightless unit; the charger's weight adds to it.
+const CHARGE_KNOCKBACK: f32 = 60.0;
+
+/// Seconds a charge staggers a weightless target.
+const CHARGE_STAGGER: f32 = 0.5;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(
+        Update,
+        charge_system
+            .in_set(AttackSet::Attack)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// Hits harder the first time it reaches an enemy after a run-up, from the `charge` column.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct Charge {
+    /// Extra share of the unit's damage dealt on impact at full speed against an equal weight.
+    pub impact: f32,
+    /// Pixels run at speed since the last impact.
+    run_up: f32,
+    /// Speed the unit last ran at.
+    momentum: f32,
+}
+
+impl Charge {
+    pub fn new(impact: f32) -> Self {
+        Self {
+            impact,
+            run_up: 0.0,
+            momentum: 0.0,
+        }
+    }
+}
+
+/// Standing units meet a charge with their weapons set, from the `brace` column:
+/// the charger takes this many times the unit's damage instead of landing its impact.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+pub struct Brace(pub f32);
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Builds up each charger's run-up while it moves and lands the impact on first contact.
+fn charge_system(
+    time: Res<Time>,
+    mut q_chargers: Query<(
+        Entity,
+        &mut Charge,
+        &UnitAction,
+        &Velocity,
+        &Target,
+        &CombatAttributes,
+        &UnitCollider,
+        &GlobalTransform,
+    )>,
+    q_targets: Query<(
+        &GlobalTransform,
+        &CombatAttributes,
+        &UnitCollider,
+        &UnitAction,
+        Option<&Brace>,
+    )>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut charge, action, velocity, target, stats, collider, global_transform) in
+        &mut q_chargers
+    {
+        match action {
+            UnitAction::Moving => {
+                let speed = velocity.0.length();
+                if speed >= stats.speed * CHARGE_MIN_SPEED_SHARE {
+                    charge.run_up += speed * delta;
+                    charge.momentum = speed;
+                } else {
+                    charge.run_up = 0.0;
+                }
+                continue;
+            }
+            UnitAction::Idle => {
+                charge.run_up = 0.0;
+                continue;
+            }
+            UnitAction::Attacking => {}
+        }
+
+        let charged = charge.run_up >= CHARGE_RUN_UP;
+        charge.run_up = 0.0;
+        if !charged {
+            continue;
+        }
+        let Some(target_entity) = target.0 else {
+            continue;
+        };
+        let Ok((target_transform, target_stats, target_collider, target_action, brace)) =
+            q_targets.get(target_entity)
+        else {
+            continue;
+        };
+
+        // A braced line that stands its ground breaks the charge on its points. Whether it
+        // stands is its own choice: shoves from separation and knockback don't count.
+        if let Some(brace) = brace.filter(|_| *target_action != UnitAction::Moving) {
+            commands.trigger(AttackEvent::new(
+                target_entity,
+                entity,
+                target_stats.damage * brace.0,
+                target_stats.damage_type,
+            ));
+            continue;
+        }
+
+        // Heavier chargers hit harder and throw targets further
+        let speed_share = (charge.momentum / stats.speed.max(1.0)).min(1.0);
+        let heft = collider.push_strength
+            / (collider.push_strength + target_collider.push_strength).max(f32::EPSILON);
+        commands.trigger(AttackEvent::new(
+            entity,
+            target_entity,
+            stats.damage * charge.impact * speed_share * 2.0 * heft,
+            stats.damage_type,
+        ));
+
+        let direction = (target_transform.translation() - global_transform.translation())
+            .truncate()
+            .normalize_or_zero();
+        commands.trigger(KnockbackEvent {
+            target: target_entity,
+            impulse: direction * CHARGE_KNOCKBACK * speed_share * (1.0 + collider.push_strength),
+            stagger: CHARGE_STAGGER * speed_share,
+        });
+    }
+}
@@ -22,19 +22,28 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
 fn attack_system(
     time: Res<Time>,
     mut q_attackers: Query<
-        (Entity, &UnitAction, &Target, &CombatAttributes, &mut AttackTimer),
+        (
+            Entity,
+            &UnitAction,
+            &Target,
+            &CombatAttributes,
+            &mut AttackTimer,
+            Option<&BelongToSquad>,
+        ),
         With<Melee>,
     >,
+    q_morale: Query<&SquadMorale>,
     mut commands: Commands,
 ) {
-    for (entity, state, target, stats, mut attack_timer) in &mut q_attackers {
+    for (entity, state, target, stats, mut attack_timer, belong_to) in &mut q_attackers {
         // Only attack when in Attacking state
         if *state != UnitAction::Attacking {
             continue;
         }
 
-        // Tick the attack timer
-        attack_timer.0.tick(time.delta());
+        // Tick the attack timer, slower while the squad's morale is shaken
+        let rate = squad_attack_rate(belong_to, &q_morale);
+        attack_timer.0.tick(time.delta().mul_f32(rate));
 
         // Check if attack is ready
         if !attack_timer.0.just_finished() {
@@ -47,7 +56,12 @@ fn attack_system(
         };
 
         // Get target's health and apply damage
-        commands.trigger(AttackEvent::new(entity, target_entity, stats.damage));
+        commands.trigger(AttackEvent::new(
+            entity,
+            target_entity,
+            stats.damage,
+            stats.damage_type,
+        ));
     }
 }
 
@@ -1,26 +1,29 @@
-mod archer;
-pub(crate) use archer::*;
+mod charge;
+pub(crate) use charge::*;
 
-mod shield;
-pub(crate) use shield::*;
+mod melee;
 
-mod spear;
-pub(crate) use spear::*;
-
-mod cavalry;
-pub(crate) use cavalry::*;
+mod ranged;
+pub(crate) use ranged::*;
 
 mod shadow;
 pub(crate) use shadow::*;
 
+mod support;
+
 mod unit;
 pub(crate) use unit::*;
 pub(crate) fn plugin(app: &mut bevy::app::App) {
-    archer::plugin(app);
-    shield::plugin(app);
-    spear::plugin(app);
-    cavalry::plugin(app);
+    headless_plugin(app);
     shadow::plugin(app);
+}
+
+/// Unit attack systems that don't need a window or loaded assets.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    melee::plugin(app);
+    charge::plugin(app);
+    ranged::plugin(app);
+    support::plugin(app);
 
     unit::plugin(app);
 }
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// Marker component for arrow projectiles, flown by [`Projectile`].
+/// Uses Actor system which auto-creates Model → MainMesh hierarchy.
+/// The Actor's Transform handles ground position (linear X/Y movement).
+/// The Model's Transform.y is used for visual arc height.
+#[derive(Component, Default, Prefab, Reflect)]
+#[require(SpriteActor, SpriteLayer::VFX, DespawnOnExit::<GameState>(GameState::Battle))]
+pub struct Arrow;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.register_type::<Arrow>();
+
+    app.add_systems(
+        Update,
+        ranged_attack_system
+            .in_set(AttackSet::Attack)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Handles ranged units firing arrows when attack timer finishes.
+fn ranged_attack_system(
+    time: Res<Time>,
+    mut q_archers: Query<
+        (
+            Entity,
+            &UnitAction,
+            &Target,
+            &CombatAttributes,
+            &mut AttackTimer,
+            &GlobalTransform,
+            &Faction,
+            &ProjectileSpec,
+            Option<&BelongToSquad>,
+        ),
+        With<Ranged>,
+    >,
+    q_targets: Query<(&GlobalTransform, Option<&Velocity>)>,
+    q_morale: Query<&SquadMorale>,
+    mut commands: Commands,
+) {
+    for (
+        archer_entity,
+        state,
+        target,
+        stats,
+        mut attack_timer,
+        archer_transform,
+        faction,
+        projectile_spec,
+        belong_to,
+    ) in &mut q_archers
+    {
+        // Only attack when in Attacking state
+        if *state != UnitAction::Attacking {
+            continue;
+        }
+
+        // Tick the attack timer, slower while the squad's morale is shaken
+        let rate = squad_attack_rate(belong_to, &q_morale);
+        attack_timer.0.tick(time.delta().mul_f32(rate));
+
+        // Check if attack is ready
+        if !attack_timer.0.just_finished() {
+            continue;
+        }
+
+        // Get target entity
+        let Some(target_entity) = target.0 else {
+            continue;
+        };
+
+        // Get target position
+        let Ok((target_transform, target_velocity)) = q_targets.get(target_entity) else {
+            continue;
+        };
+
+        let start_pos = archer_transform.translation().truncate();
+        let projectile = Projectile::new(
+            *projectile_spec,
+            stats.damage,
+            stats.damage_type,
+            archer_entity,
+            *faction,
+        )
+        .aimed_at(
+            start_pos,
+            target_entity,
+            target_transform.translation().truncate(),
+            target_velocity.map_or(Vec2::ZERO, |velocity| velocity.0),
+        );
+
+        // Spawn the arrow projectile
+        spawn_arrow(&mut commands, start_pos, projectile);
+        commands.trigger(SFXEvent::space("arrow", start_pos).with_random_pitch(0.9, 1.1));
+    }
+}
+
+/// Spawns an arrow projectile.
+///
+/// Structure (via Actor system):
+/// - Arrow (Actor): Ground position, moves linearly on X/Y
+///   - Model: Visual offset, Y adjusted for arc height
+///     - MainMesh: Actual sprite (loaded from aseprite)
+fn spawn_arrow(commands: &mut Commands, start: Vec2, projectile: Projectile) {
+    // Calculate initial rotation to face the landing point
+    let direction = (projectile.landing_point() - start).normalize_or_zero();
+    let angle = direction.y.atan2(direction.x);
+
+    commands.spawn((
+        Arrow,
+        projectile,
+        Transform::from_translation(Vec3::new(start.x, start.y, 0.0))
+            .with_rotation(Quat::from_rotation_z(angle)),
+        Name::new("Arrow"),
+    ));
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(Update, heal_over_time_system.in_set(AttackSet::Attack));
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Support units mend the ally they tend: every swing heals it by the unit's damage,
+/// cleanses a debuff and hands out the healer's `on_attack` effects, such as heal over time.
+fn heal_over_time_system(
+    time: Res<Time>,
+    registry: Res<EffectRegistry>,
+    mut rng: ResMut<BattleRng>,
+    mut q_healers: Query<
+        (
+            Entity,
+            &UnitAction,
+            &Target,
+            &CombatAttributes,
+            &mut AttackTimer,
+            Option<&BelongToSquad>,
+        ),
+        With<Support>,
+    >,
+    q_health: Query<&Health>,
+    mut q_effects: Query<&mut ActiveEffects>,
+    q_morale: Query<&SquadMorale>,
+    mut ev_heal: MessageWriter<HealMessage>,
+) {
+    for (entity, state, target, stats, mut attack_timer, belong_to) in &mut q_healers {
+        if *state != UnitAction::Attacking {
+            continue;
+        }
+
+        // Same cadence as an attack, slower while the squad's morale is shaken
+        let rate = squad_attack_rate(belong_to, &q_morale);
+        attack_timer.0.tick(time.delta().mul_f32(rate));
+        if !attack_timer.0.just_finished() {
+            continue;
+        }
+
+        let Some(ally) = target.0 else {
+            continue;
+        };
+        if q_health.get(ally).is_ok_and(Health::is_injured) {
+            ev_heal.write(HealMessage {
+                target: ally,
+                amount: stats.damage,
+            });
+        }
+
+        let applied = q_effects
+            .get(entity)
+            .map(|effects| effects.roll_on_attack(&registry, &mut **rng))
+            .unwrap_or_default();
+        let Ok(mut ally_effects) = q_effects.get_mut(ally) else {
+            continue;
+        };
+        ally_effects.cleanse(&registry);
+        for (definition, stacks) in applied {
+            ally_effects.apply(definition, stacks);
+        }
+    }
+}
@@ -1,25 +1,147 @@
-use strum::{AsRefStr, EnumCount, EnumIter};
+use smol_str::SmolStr;
 
 use crate::prelude::*;
-pub(crate) fn plugin(_app: &mut bevy::app::App) {}
-/// Unit kind for targeting
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.init_resource::<NextUnitId>();
+    app.add_systems(OnEnter(GameState::Battle), reset_unit_ids);
+    app.add_systems(
+        Update,
+        number_new_units
+            .before(MovementSet::VelocityTracking)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+/// A unit's number in the current battle.
+///
+/// Entity indices depend on everything spawned before the battle, so a replay gets
+/// different ones; ids follow the squads and formation slots and come out the same.
+/// Ties, time slicing and spreading out targets are keyed on them.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnitId(pub u32);
+
+/// The id the next unit to join this battle gets.
+#[derive(Resource, Default)]
+struct NextUnitId(u32);
+
+fn reset_unit_ids(mut next_id: ResMut<NextUnitId>) {
+    *next_id = NextUnitId::default();
+}
+
+/// Numbers units without an id by [`battle_order`] of their squads, then formation slot.
+/// Units on the field when the battle starts are all numbered on its first frame.
+fn number_new_units(
+    mut next_id: ResMut<NextUnitId>,
+    q_units: Query<
+        (Entity, Option<&BelongToSquad>, Option<&FormationSlot>),
+        (With<Unit>, Without<UnitId>),
+    >,
+    q_squads: Query<(&Squad, &Faction, &GlobalTransform)>,
+    mut commands: Commands,
+) {
+    let mut units: Vec<_> = q_units
+        .iter()
+        .map(|(entity, belong_to, slot)| {
+            let squad = belong_to.and_then(|belong_to| q_squads.get(belong_to.0).ok());
+            (entity, squad, slot.map_or(usize::MAX, |slot| slot.0))
+        })
+        .collect();
+    units.sort_by(|(_, a_squad, a_slot), (_, b_squad, b_slot)| {
+        let squads = match (a_squad, b_squad) {
+            (Some(a), Some(b)) => battle_order(*a, *b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        };
+        squads.then(a_slot.cmp(b_slot))
+    });
+
+    for (entity, ..) in units {
+        commands.entity(entity).insert(UnitId(next_id.0));
+        next_id.0 += 1;
+    }
+}
+
+/// Unit kind for targeting: the `id` of the unit's row in `all.unit.csv`.
+///
+/// Kinds are data, not code, so a new unit only needs a new CSV row.
 #[derive(
     serde::Deserialize,
+    serde::Serialize,
     Component,
     Debug,
     Clone,
-    Copy,
     PartialEq,
     Eq,
     Hash,
+    PartialOrd,
+    Ord,
     Reflect,
-    AsRefStr,
-    EnumIter,
-    EnumCount,
 )]
-pub enum UnitKind {
-    Shield,
-    Spear,
-    Archer,
-    Cavalry,
+#[serde(transparent)]
+#[reflect(opaque)]
+pub struct UnitKind(SmolStr);
+
+impl UnitKind {
+    pub fn new(id: &str) -> Self {
+        Self(SmolStr::new(id))
+    }
+}
+
+impl AsRef<str> for UnitKind {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for UnitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// How a unit deals damage, from the `attack` column.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum AttackStyle {
+    /// Hits its target directly once in range.
+    Melee,
+    /// Fires arrows at its target from range.
+    Ranged,
+    /// Heals the most wounded ally nearby.
+    Support,
+}
+
+/// Turns a freshly spawned entity into a unit of the given definition.
+///
+/// Stats are filled in by `apply_unit_stats_from_csv`; this only adds what the unit
+/// is made of: its sprite, attack style, charge or brace, targeting, starting buffs
+/// and kind.
+pub fn insert_unit(entity_commands: &mut EntityCommands, definition: &UnitRow) {
+    let kind = UnitKind::new(&definition.id);
+    entity_commands.insert((
+        SpriteActor,
+        ActorSprite(definition.sprite.clone()),
+        UnitGameName(definition.game_unit_name.as_str().into()),
+        Pawn,
+        Health::new_full(definition.hp),
+        ActiveEffects::innate(&definition.buffs),
+        definition.targeting,
+        kind,
+    ));
+
+    let kind = definition.unity_type.clone();
+    match definition.attack {
+        AttackStyle::Melee => entity_commands.insert((Melee, CombatAttributes::melee(kind))),
+        AttackStyle::Ranged => entity_commands.insert((
+            Ranged,
+            CombatAttributes::ranged(kind),
+            definition.projectile,
+        )),
+        AttackStyle::Support => entity_commands.insert((Support, CombatAttributes::ranged(kind))),
+    };
+
+    if let Some(impact) = definition.charge {
+        entity_commands.insert(Charge::new(impact));
+    }
+    if let Some(brace) = definition.brace {
+        entity_commands.insert(Brace(brace));
+    }
 }
@@ -64,19 +64,31 @@ pub(crate) fn plugin(app: &mut App) {
         commands.trigger(BGMEvent::new("prepare"));
     });
 
-    app.add_systems(OnEnter(GameState::Battle), |mut commands: Commands| {
-        commands.trigger(BGMEvent::new("battle"));
-    });
+    app.add_systems(OnEnter(GameState::Battle), play_night_battle_music);
 }
 
 #[cfg(not(feature = "backend"))]
 pub(crate) fn plugin(_app: &mut App) {}
 
+/// Plays the battle music the campaign picked for the night.
+#[cfg(feature = "backend")]
+fn play_night_battle_music(
+    progress: Res<GameProgress>,
+    campaign: Res<Campaign>,
+    mut commands: Commands,
+) {
+    let music = campaign
+        .night(progress.current_round)
+        .map_or("battle", |night| night.music.as_str());
+    commands.trigger(BGMEvent::new(music));
+}
+
 /// Plays background music with fade transitions
 #[cfg(feature = "backend")]
 fn play_bgm(
     trigger: On<BGMEvent>,
     music_assets: Res<MusicAssets>,
+    asset_server: Res<AssetServer>,
     mut current_music: ResMut<CurrentBGM>,
     mut volume_nodes: Query<(&VolumeNode, &mut AudioEvents)>,
     sample_effects: Query<&SampleEffects>,
@@ -110,14 +122,11 @@ fn play_bgm(
         commands.entity(prev_entity).despawn();
     }
 
-    // Get the music handle based on the ID
+    // Get the music handle based on the ID; other tracks are streamed in by name
     let music_handle = match trigger.id.as_str() {
         "prepare" => music_assets.prepare.clone(),
         "battle" => music_assets.battle.clone(),
-        _ => {
-            warn!("Unknown BGM ID: {}", trigger.id);
-            return;
-        }
+        id => asset_server.load(format!("audio/music/{id}.ogg")),
     };
 
     // Spawn the new music entity with looping and volume effects starting at silent
@@ -7,7 +7,11 @@ pub struct StatsInitialized;
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.add_systems(
         Update,
-        apply_unit_stats_from_csv.in_set(BattleSystems::UpdateUnitValue),
+        (
+            apply_unit_stats_from_csv,
+            reapply_unit_stats.run_if(resource_changed::<UnitStatsCache>),
+        )
+            .in_set(BattleSystems::UpdateUnitValue),
     );
 }
 
@@ -16,14 +20,10 @@ fn apply_unit_stats_from_csv(
     mut q_units: Query<
         (
             Entity,
+            &UnitKind,
             &mut CombatAttributes,
             &mut Health,
             &mut UnitCollider,
-            Option<&Shield>,
-            Option<&Archer>,
-            Option<&Cavalry>,
-            Option<&Spear>,
-            Option<&EnemyFaction>,
         ),
         (With<Unit>, Without<StatsInitialized>),
     >,
@@ -33,57 +33,86 @@ fn apply_unit_stats_from_csv(
         return;
     }
 
-    for (
-        entity,
-        mut stats,
-        mut health,
-        mut collider,
-        is_shield,
-        is_archer,
-        is_cavalry,
-        is_spear,
-        _is_enemy,
-    ) in &mut q_units
-    {
-        // Determine unit ID (must match CSV id column)
-        let unit_id = if is_shield.is_some() {
-            "Shield"
-        } else if is_archer.is_some() {
-            "Archer"
-        } else if is_cavalry.is_some() {
-            "Cavalry"
-        } else if is_spear.is_some() {
-            "Spear"
-        } else {
-            // Skip unknown unit types
-            continue;
-        };
-
+    for (entity, kind, mut stats, mut health, mut collider) in &mut q_units {
         // Apply stats from cache
-        if let Some(row) = cache.stats.get(unit_id) {
-            // Apply UnitStats
-            stats.damage = row.atk;
-            stats.attack_speed = row.atk_speed;
-            stats.speed = row.move_speed;
-            stats.attack_range = row.range;
-            stats.defense = row.def;
-            stats.unity_kind = row.unity_type;
-            stats.counter = row.counter;
+        if let Some(row) = cache.get(kind) {
+            apply_row(row, &mut stats, &mut collider);
+            commands
+                .entity(entity)
+                .insert(BaseCombatAttributes(stats.clone()));
 
             // Apply Health
             *health = Health::new_full(row.hp);
 
-            // Apply weight to collider
-            collider.push_strength = row.weight;
-
             commands
                 .entity(entity)
                 .insert(UnitGameName(row.game_unit_name.as_str().into()));
 
-            // info!("Applied CSV stats to {:?} ({})", entity, unit_id);
+            // info!("Applied CSV stats to {:?} ({})", entity, kind);
+        } else {
+            warn!("No stats found for unit kind {kind}");
         }
 
         // Mark as initialized
         commands.entity(entity).insert(StatsInitialized);
     }
 }
+
+/// Pushes a hot-reloaded balance sheet onto every living unit.
+///
+/// Wounded units keep the same share of their health.
+fn reapply_unit_stats(
+    mut commands: Commands,
+    mut q_units: Query<
+        (
+            Entity,
+            &UnitKind,
+            &mut CombatAttributes,
+            &mut Health,
+            &mut UnitCollider,
+        ),
+        (With<Unit>, With<StatsInitialized>),
+    >,
+    cache: Res<UnitStatsCache>,
+) {
+    for (entity, kind, mut stats, mut health, mut collider) in &mut q_units {
+        if !health.is_alive() {
+            continue;
+        }
+        let Some(row) = cache.get(kind) else {
+            continue;
+        };
+
+        apply_row(row, &mut stats, &mut collider);
+        commands
+            .entity(entity)
+            .insert(BaseCombatAttributes(stats.clone()));
+
+        let health_share = health.get_current() / health.get_max();
+        *health = Health::new(row.hp * health_share, row.hp);
+
+        commands
+            .entity(entity)
+            .insert(UnitGameName(row.game_unit_name.as_str().into()));
+    }
+}
+
+fn apply_row(row: &UnitRow, stats: &mut CombatAttributes, collider: &mut UnitCollider) {
+    stats.damage = row.atk;
+    stats.attack_speed = row.atk_speed;
+    stats.speed = row.move_speed;
+    stats.attack_range = row.range;
+    stats.defense = row.def;
+    stats.unity_kind = row.unity_type.clone();
+    stats.counter = row.counter.clone();
+    stats.damage_type = row.damage_type;
+    stats.resistances = Resistances {
+        slash: row.res_slash,
+        pierce: row.res_pierce,
+        blunt: row.res_blunt,
+        dream: row.res_dream,
+    };
+
+    // Apply weight to collider
+    collider.push_strength = row.weight;
+}
@@ -0,0 +1,109 @@
+//! `check-balance` command: validates the balance sheets without starting the game.
+//!
+//! ```text
+//! bevy_game check-balance [--units assets/balance/all.unit.csv]
+//!                         [--memories assets/balance/all.memory.csv]
+//!                         [--effects assets/balance/all.effect.csv]
+//!                         [--level assets/chaos_dream.ldtk]
+//! ```
+
+use anyhow::{Context, bail};
+use bevy_ecs_ldtk::ldtk::LdtkJson;
+
+use crate::prelude::*;
+
+const DEFAULT_UNITS: &str = "assets/balance/all.unit.csv";
+const DEFAULT_MEMORIES: &str = "assets/balance/all.memory.csv";
+const DEFAULT_EFFECTS: &str = "assets/balance/all.effect.csv";
+const DEFAULT_LEVEL: &str = "assets/chaos_dream.ldtk";
+
+struct CheckArgs {
+    units: String,
+    memories: String,
+    effects: String,
+    level: String,
+}
+
+/// Runs the command with the arguments that follow `check-balance`.
+pub(crate) fn run_check_command(args: impl Iterator<Item = String>) -> AppExit {
+    match run(args) {
+        Ok(0) => {
+            eprintln!("check-balance: no issues");
+            AppExit::Success
+        }
+        Ok(count) => {
+            eprintln!("check-balance: {count} issues");
+            AppExit::error()
+        }
+        Err(error) => {
+            eprintln!("check-balance: {error:#}");
+            AppExit::error()
+        }
+    }
+}
+
+/// Prints every issue and returns how many there were.
+fn run(args: impl Iterator<Item = String>) -> anyhow::Result<usize> {
+    let args = parse_args(args)?;
+
+    let units_source = read(&args.units)?;
+    let memories_source = read(&args.memories)?;
+    let effects_source = read(&args.effects)?;
+    let level: LdtkJson = serde_json::from_str(&read(&args.level)?)
+        .with_context(|| format!("parsing {}", args.level))?;
+
+    let (units, mut issues) = parse_sheet::<UnitRow>(UNIT_SHEET, &units_source);
+    let (memories, memory_parse_issues) = parse_sheet::<MemoryRow>(MEMORY_SHEET, &memories_source);
+    let (effects, effect_parse_issues) = parse_sheet::<EffectRow>(EFFECT_SHEET, &effects_source);
+    issues.extend(memory_parse_issues);
+    issues.extend(effect_parse_issues);
+
+    issues.extend(validate_units(
+        units.iter().map(|(line, row)| (*line, row)),
+        &ldtk_unit_references(&level),
+    ));
+    issues.extend(validate_memories(
+        memories.iter().map(|(line, row)| (*line, row)),
+        &memory_references(),
+    ));
+    issues.extend(validate_effects(
+        effects.iter().map(|(line, row)| (*line, row)),
+        &effect_references(
+            units.iter().map(|(_, row)| row),
+            effects.iter().map(|(_, row)| row),
+        ),
+    ));
+
+    for issue in &issues {
+        println!("{issue}");
+    }
+    Ok(issues.len())
+}
+
+fn read(path: &str) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("reading {path}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<CheckArgs> {
+    let mut parsed = CheckArgs {
+        units: DEFAULT_UNITS.to_string(),
+        memories: DEFAULT_MEMORIES.to_string(),
+        effects: DEFAULT_EFFECTS.to_string(),
+        level: DEFAULT_LEVEL.to_string(),
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .with_context(|| format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--units" => parsed.units = value,
+            "--memories" => parsed.memories = value,
+            "--effects" => parsed.effects = value,
+            "--level" => parsed.level = value,
+            other => bail!("unknown argument {other}"),
+        }
+    }
+
+    Ok(parsed)
+}
@@ -0,0 +1,55 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use crate::prelude::*;
+
+/// Records `name: old -> new` if a field differs between two versions of a row.
+pub(super) fn diff_field<T: Debug>(changes: &mut Vec<String>, name: &str, old: &T, new: &T) {
+    let (old, new) = (format!("{old:?}"), format!("{new:?}"));
+    if old != new {
+        changes.push(format!("{name}: {old} -> {new}"));
+    }
+}
+
+/// Lists the changes between two rows, one [`diff_field`] per named field.
+macro_rules! changed_fields {
+    ($old:expr, $new:expr, [$($field:ident),* $(,)?]) => {{
+        let mut changes = Vec::new();
+        $(super::diff::diff_field(&mut changes, stringify!($field), &$old.$field, &$new.$field);)*
+        changes
+    }};
+}
+pub(super) use changed_fields;
+
+/// Logs every row of a reloaded balance sheet that was added, removed or changed.
+pub(super) fn log_sheet_changes<R>(
+    sheet: &str,
+    old: &HashMap<String, R>,
+    new: &HashMap<String, R>,
+    changed_fields: impl Fn(&R, &R) -> Vec<String>,
+) {
+    let mut ids: Vec<&String> = old.keys().chain(new.keys()).collect();
+    ids.sort();
+    ids.dedup();
+
+    let mut change_count = 0;
+    for id in ids {
+        match (old.get(id), new.get(id)) {
+            (Some(old_row), Some(new_row)) => {
+                for change in changed_fields(old_row, new_row) {
+                    info!("[Balance] {sheet} {id}: {change}");
+                    change_count += 1;
+                }
+            }
+            (None, Some(_)) => {
+                info!("[Balance] {sheet} {id}: added");
+                change_count += 1;
+            }
+            (Some(_), None) => {
+                info!("[Balance] {sheet} {id}: removed");
+                change_count += 1;
+            }
+            (None, None) => {}
+        }
+    }
+    info!("[Balance] Reloaded {sheet} with {change_count} changes");
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+
+use bevy_common_assets::csv::{CsvAssetPlugin, LoadedCsv};
+
+use super::diff::{changed_fields, log_sheet_changes};
+use crate::{asset_tracking::LoadResource, prelude::*, screens::loading::LoadingScreen};
+
+/// The sheet as shipped, for tools and tests that run without an `AssetServer`.
+const SHIPPED_EFFECTS: &str = include_str!("../../../assets/balance/all.effect.csv");
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_plugins(CsvAssetPlugin::<EffectRow>::new(&["effect.csv"]));
+    app.add_systems(OnEnter(LoadingScreen::Level), build_effect_registry);
+    app.add_systems(
+        Update,
+        reload_effect_registry.run_if(resource_exists::<EffectBalanceAssets>),
+    );
+
+    app.load_resource::<EffectBalanceAssets>();
+}
+
+/// Every status effect a unit can carry, indexed by effect ID.
+#[derive(Resource, Clone, Reflect)]
+pub struct EffectRegistry {
+    pub effects: HashMap<String, EffectRow>,
+}
+
+/// Starts out with the shipped sheet; the loaded asset replaces it once it's in.
+impl Default for EffectRegistry {
+    fn default() -> Self {
+        Self::from_csv_str(SHIPPED_EFFECTS).expect("shipped all.effect.csv should parse")
+    }
+}
+
+impl EffectRegistry {
+    /// Builds the registry straight from CSV text, for tools that run without an `AssetServer`.
+    pub fn from_csv_str(source: &str) -> Result<Self, csv::Error> {
+        let mut reader = csv::Reader::from_reader(source.as_bytes());
+        let mut effects = HashMap::new();
+        for row in reader.deserialize::<EffectRow>() {
+            let row = row?;
+            effects.insert(row.id.clone(), row);
+        }
+        Ok(Self { effects })
+    }
+
+    pub fn get(&self, id: &EffectId) -> Option<&EffectRow> {
+        self.effects.get(id.as_ref())
+    }
+}
+
+fn build_effect_registry(
+    mut registry: ResMut<EffectRegistry>,
+    effect_assets: Res<EffectBalanceAssets>,
+    csv_assets: Res<Assets<LoadedCsv<EffectRow>>>,
+) {
+    let Some(loaded) = csv_assets.get(&effect_assets.effects) else {
+        warn!("EffectAssets CSV not loaded yet");
+        return;
+    };
+
+    registry.effects.clear();
+    for row in &loaded.rows {
+        registry.effects.insert(row.id.clone(), row.clone());
+    }
+    info!(
+        "Built EffectRegistry with {} entries",
+        registry.effects.len()
+    );
+}
+
+/// Rebuilds the registry when `all.effect.csv` is edited while the game runs.
+fn reload_effect_registry(
+    mut asset_events: MessageReader<AssetEvent<LoadedCsv<EffectRow>>>,
+    mut registry: ResMut<EffectRegistry>,
+    effect_assets: Res<EffectBalanceAssets>,
+    csv_assets: Res<Assets<LoadedCsv<EffectRow>>>,
+) {
+    let modified = asset_events
+        .read()
+        .any(|event| event.is_modified(&effect_assets.effects));
+    if !modified {
+        return;
+    }
+    let Some(loaded) = csv_assets.get(&effect_assets.effects) else {
+        return;
+    };
+
+    let effects: HashMap<String, EffectRow> = loaded
+        .rows
+        .iter()
+        .map(|row| (row.id.clone(), row.clone()))
+        .collect();
+    log_sheet_changes(
+        "all.effect.csv",
+        &registry.effects,
+        &effects,
+        EffectRow::changed_fields,
+    );
+    registry.effects = effects;
+}
+
+/// How applying an effect a unit already has combines with the stacks it has.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum EffectStacking {
+    /// Stacks add up, to `max_stacks`.
+    Add,
+    /// Keeps the larger stack count.
+    Refresh,
+}
+
+/// What one stack of an effect does to a stat, e.g. `Speed:-15%` or `Defense:-1`.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct EffectModifier {
+    pub stat: Stat,
+    pub bucket: ModifierBucket,
+    pub per_stack: f32,
+}
+
+impl EffectModifier {
+    pub fn from_spec(spec: &str) -> Option<Self> {
+        let (name, amount) = spec.split_once(':')?;
+        let stat = Stat::from_name(name.trim())?;
+        let amount = amount.trim();
+        Some(match amount.strip_suffix('%') {
+            Some(percent) => Self {
+                stat,
+                bucket: ModifierBucket::Percent,
+                per_stack: percent.trim().parse::<f32>().ok()? / 100.0,
+            },
+            None => Self {
+                stat,
+                bucket: ModifierBucket::Flat,
+                per_stack: amount.parse().ok()?,
+            },
+        })
+    }
+}
+
+/// One status effect from `all.effect.csv`. Empty cells leave that part out.
+#[derive(serde::Deserialize, Asset, Debug, Clone, Reflect)]
+pub struct EffectRow {
+    pub id: String,
+    pub max_stacks: u32,
+    pub stacking: EffectStacking,
+    /// Seconds to regain a stack, up to `max_stacks`.
+    pub regen: Option<f32>,
+    /// Seconds the effect lasts once applied; innate effects never run out.
+    pub duration: Option<f32>,
+    /// Seconds between `tick_damage` hits.
+    pub tick: Option<f32>,
+    /// Damage per stack dealt to the carrier every `tick`; negative heals it.
+    pub tick_damage: Option<f32>,
+    /// Stacks lost every `tick`. The effect ends when they run out.
+    pub tick_decay: Option<u32>,
+    /// Chance per stack that `on_attack` or `on_hit_damage` happens; always when empty.
+    pub chance: Option<f32>,
+    /// Effect put on the target when the carrier attacks, see [`EffectSpec::from_spec`].
+    /// Without `:stacks` it gets as many stacks as the carrier has.
+    #[serde(deserialize_with = "deserialize_optional_effect")]
+    pub on_attack: Option<EffectSpec>,
+    /// Multiplies damage the carrier takes, using up a stack.
+    pub on_hit_damage: Option<f32>,
+    /// Stat change per stack.
+    #[serde(deserialize_with = "deserialize_optional_modifier")]
+    pub modifier: Option<EffectModifier>,
+    /// The carrier can't attack.
+    #[serde(deserialize_with = "deserialize_flag")]
+    pub stuns: bool,
+    /// A harmful status that healers can cleanse.
+    #[serde(deserialize_with = "deserialize_flag")]
+    pub debuff: bool,
+    /// Effect given every second to the carrier's allies nearby, see [`EffectSpec::from_spec`].
+    #[serde(deserialize_with = "deserialize_optional_effect")]
+    pub aura: Option<EffectSpec>,
+    /// Tooltip text. `{stacks}`, `{max}`, `{chance}` (percent), `{amount}` (size of the
+    /// stat change) and `{remaining}` (seconds) are filled in.
+    pub text: String,
+}
+
+impl EffectRow {
+    /// The tooltip text for `effect`, with its numbers filled in.
+    pub fn describe(&self, effect: &ActiveEffect) -> String {
+        let chance = self.chance.unwrap_or(1.0) * effect.stacks as f32 * 100.0;
+        let amount = self.modifier.map_or(0.0, |modifier| {
+            let amount = (modifier.per_stack * effect.stacks as f32).abs();
+            match modifier.bucket {
+                ModifierBucket::Flat => amount,
+                ModifierBucket::Percent => amount * 100.0,
+            }
+        });
+        self.text
+            .replace("{stacks}", &effect.stacks.to_string())
+            .replace("{max}", &self.max_stacks.to_string())
+            .replace("{chance}", &format!("{chance:.0}"))
+            .replace("{amount}", &format!("{amount:.0}"))
+            .replace(
+                "{remaining}",
+                &format!("{:.0}", effect.remaining.unwrap_or_default()),
+            )
+    }
+
+    /// Describes every field that differs in `new`, for the hot-reload log.
+    fn changed_fields(&self, new: &Self) -> Vec<String> {
+        changed_fields!(
+            self,
+            new,
+            [
+                max_stacks,
+                stacking,
+                regen,
+                duration,
+                tick,
+                tick_damage,
+                tick_decay,
+                chance,
+                on_attack,
+                on_hit_damage,
+                modifier,
+                stuns,
+                debuff,
+                aura,
+                text
+            ]
+        )
+    }
+}
+
+fn deserialize_optional_effect<'de, D>(deserializer: D) -> Result<Option<EffectSpec>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    match s.trim() {
+        "" => Ok(None),
+        spec => EffectSpec::from_spec(spec)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("bad effect `{spec}`"))),
+    }
+}
+
+fn deserialize_optional_modifier<'de, D>(
+    deserializer: D,
+) -> Result<Option<EffectModifier>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    match s.trim() {
+        "" => Ok(None),
+        spec => EffectModifier::from_spec(spec).map(Some).ok_or_else(|| {
+            serde::de::Error::custom(format!("bad modifier `{spec}`, expected e.g. `Speed:-10%`"))
+        }),
+    }
+}
+
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    match s.trim() {
+        "" | "false" => Ok(false),
+        "true" => Ok(true),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"`true`, `false` or empty",
+        )),
+    }
+}
+
+#[derive(Resource, Asset, Clone, TypePath)]
+pub(crate) struct EffectBalanceAssets {
+    #[dependency]
+    pub(crate) effects: Handle<LoadedCsv<EffectRow>>,
+}
+
+impl FromWorld for EffectBalanceAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+
+        Self {
+            effects: assets.load("balance/all.effect.csv"),
+        }
+    }
+}
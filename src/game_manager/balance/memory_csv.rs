@@ -2,12 +2,17 @@ use std::collections::HashMap;
 
 use bevy_common_assets::csv::{CsvAssetPlugin, LoadedCsv};
 
+use super::diff::{changed_fields, log_sheet_changes};
 use crate::{asset_tracking::LoadResource, prelude::*, screens::loading::LoadingScreen};
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.add_plugins(CsvAssetPlugin::<MemoryRow>::new(&["memory.csv"]));
     app.init_resource::<MemoryStatsCache>();
     app.add_systems(OnEnter(LoadingScreen::Level), build_memory_stats_cache);
+    app.add_systems(
+        Update,
+        reload_memory_stats_cache.run_if(resource_exists::<MemoryBalanceAssets>),
+    );
 
     app.load_resource::<MemoryBalanceAssets>();
 }
@@ -35,6 +40,37 @@ fn build_memory_stats_cache(
     info!("Built MemoryStatsCache with {} entries", cache.stats.len());
 }
 
+/// Rebuilds the cache when `all.memory.csv` is edited while the game runs.
+fn reload_memory_stats_cache(
+    mut asset_events: MessageReader<AssetEvent<LoadedCsv<MemoryRow>>>,
+    mut cache: ResMut<MemoryStatsCache>,
+    memory_assets: Res<MemoryBalanceAssets>,
+    csv_assets: Res<Assets<LoadedCsv<MemoryRow>>>,
+) {
+    let modified = asset_events
+        .read()
+        .any(|event| event.is_modified(&memory_assets.memories));
+    if !modified {
+        return;
+    }
+    let Some(loaded) = csv_assets.get(&memory_assets.memories) else {
+        return;
+    };
+
+    let stats: HashMap<String, MemoryRow> = loaded
+        .rows
+        .iter()
+        .map(|row| (row.id.clone(), row.clone()))
+        .collect();
+    log_sheet_changes(
+        "all.memory.csv",
+        &cache.stats,
+        &stats,
+        MemoryRow::changed_fields,
+    );
+    cache.stats = stats;
+}
+
 #[derive(serde::Deserialize, Asset, Debug, Clone, Reflect)]
 pub struct MemoryRow {
     pub id: String,
@@ -43,6 +79,13 @@ pub struct MemoryRow {
     pub description: String,
 }
 
+impl MemoryRow {
+    /// Describes every field that differs in `new`, for the hot-reload log.
+    fn changed_fields(&self, new: &Self) -> Vec<String> {
+        changed_fields!(self, new, [name, price, description])
+    }
+}
+
 #[derive(Resource, Asset, Clone, TypePath)]
 pub(crate) struct MemoryBalanceAssets {
     #[dependency]
@@ -5,10 +5,26 @@ pub(crate) use units_csv::*;
 mod memory_csv;
 pub(crate) use memory_csv::*;
 
+mod effects_csv;
+pub(crate) use effects_csv::*;
+
+mod validate;
+pub(crate) use validate::*;
+
 mod apply_unit;
+mod check;
+pub(crate) use check::run_check_command;
+mod diff;
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     units_csv::plugin(app);
     memory_csv::plugin(app);
+    effects_csv::plugin(app);
+    validate::plugin(app);
+    headless_plugin(app);
+}
+
+/// Applies cached unit stats without loading any assets; the cache must be filled by the caller.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
     apply_unit::plugin(app);
 }
@@ -2,22 +2,52 @@ use std::collections::HashMap;
 
 use bevy_common_assets::csv::{CsvAssetPlugin, LoadedCsv};
 
+use super::diff::{changed_fields, log_sheet_changes};
 use crate::{asset_tracking::LoadResource, prelude::*, screens::loading::LoadingScreen};
 
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     app.add_plugins(CsvAssetPlugin::<UnitRow>::new(&["unit.csv"]));
     app.init_resource::<UnitStatsCache>();
     app.add_systems(OnEnter(LoadingScreen::Level), build_unit_stats_cache);
+    app.add_systems(
+        Update,
+        reload_unit_stats_cache.run_if(resource_exists::<UnitBalanceAssets>),
+    );
 
     app.load_resource::<UnitBalanceAssets>();
 }
 
 /// Cache of unit stats indexed by unit ID for fast lookup.
-#[derive(Resource, Default, Reflect)]
+#[derive(Resource, Default, Clone, Reflect)]
 pub struct UnitStatsCache {
     pub stats: HashMap<String, UnitRow>,
 }
 
+impl UnitStatsCache {
+    /// Builds the cache straight from CSV text, for tools that run without an `AssetServer`.
+    pub fn from_csv_str(source: &str) -> Result<Self, csv::Error> {
+        let mut reader = csv::Reader::from_reader(source.as_bytes());
+        let mut stats = HashMap::new();
+        for row in reader.deserialize::<UnitRow>() {
+            let row = row?;
+            stats.insert(row.id.clone(), row);
+        }
+        Ok(Self { stats })
+    }
+
+    /// Looks up the row a unit of `kind` is built from.
+    pub fn get(&self, kind: &UnitKind) -> Option<&UnitRow> {
+        self.stats.get(kind.as_ref())
+    }
+
+    /// Every unit kind in the sheet, sorted by id.
+    pub fn kinds(&self) -> Vec<UnitKind> {
+        let mut kinds: Vec<UnitKind> = self.stats.keys().map(|id| UnitKind::new(id)).collect();
+        kinds.sort();
+        kinds
+    }
+}
+
 fn build_unit_stats_cache(
     mut cache: ResMut<UnitStatsCache>,
     unit_assets: Res<UnitBalanceAssets>,
@@ -35,6 +65,37 @@ fn build_unit_stats_cache(
     info!("Built UnitStatsCache with {} entries", cache.stats.len());
 }
 
+/// Rebuilds the cache when `all.unit.csv` is edited while the game runs.
+fn reload_unit_stats_cache(
+    mut asset_events: MessageReader<AssetEvent<LoadedCsv<UnitRow>>>,
+    mut cache: ResMut<UnitStatsCache>,
+    unit_assets: Res<UnitBalanceAssets>,
+    csv_assets: Res<Assets<LoadedCsv<UnitRow>>>,
+) {
+    let modified = asset_events
+        .read()
+        .any(|event| event.is_modified(&unit_assets.units));
+    if !modified {
+        return;
+    }
+    let Some(loaded) = csv_assets.get(&unit_assets.units) else {
+        return;
+    };
+
+    let stats: HashMap<String, UnitRow> = loaded
+        .rows
+        .iter()
+        .map(|row| (row.id.clone(), row.clone()))
+        .collect();
+    log_sheet_changes(
+        "all.unit.csv",
+        &cache.stats,
+        &stats,
+        UnitRow::changed_fields,
+    );
+    cache.stats = stats;
+}
+
 #[derive(serde::Deserialize, Asset, Debug, Clone, Reflect)]
 pub struct UnitRow {
     pub id: String,
@@ -51,6 +112,63 @@ pub struct UnitRow {
     #[serde(deserialize_with = "deserialize_optional_unit_kind")]
     pub counter: Option<UnitKind>,
     pub unity_type: UnitKind,
+    pub attack: AttackStyle,
+    /// How the unit's shots fly and hit, for ranged units; see [`ProjectileSpec::from_spec`].
+    #[serde(default, deserialize_with = "deserialize_projectile")]
+    pub projectile: ProjectileSpec,
+    pub damage_type: DamageType,
+    /// Share of each damage type shrugged off: `0.25` takes 25% less, `-0.1` takes 10% more.
+    pub res_slash: f32,
+    pub res_pierce: f32,
+    pub res_blunt: f32,
+    pub res_dream: f32,
+    /// Extra damage share of a charge at full speed; empty for units that don't charge.
+    pub charge: Option<f32>,
+    /// Damage multiplier against chargers while standing; empty for units that don't brace.
+    pub brace: Option<f32>,
+    /// How the unit picks its next target.
+    pub targeting: TargetPolicy,
+    /// Effects the unit starts every battle with, separated by `;`; see [`EffectSpec::from_spec`].
+    #[serde(deserialize_with = "deserialize_buffs")]
+    pub buffs: Vec<EffectSpec>,
+    /// Image name under `procreate/`; enemies use the `Enemy`-suffixed variant.
+    pub sprite: String,
+}
+
+impl UnitRow {
+    /// Describes every field that differs in `new`, for the hot-reload log.
+    fn changed_fields(&self, new: &Self) -> Vec<String> {
+        changed_fields!(
+            self,
+            new,
+            [
+                hp,
+                atk,
+                def,
+                atk_speed,
+                move_speed,
+                range,
+                weight,
+                cost,
+                game_unit_name,
+                desc,
+                counter,
+                unity_type,
+                attack,
+                projectile,
+                damage_type,
+                res_slash,
+                res_pierce,
+                res_blunt,
+                res_dream,
+                charge,
+                brace,
+                targeting,
+                buffs,
+                sprite
+            ]
+        )
+    }
 }
 
 fn deserialize_optional_unit_kind<'de, D>(deserializer: D) -> Result<Option<UnitKind>, D::Error>
@@ -60,17 +178,34 @@ where
     let s: String = serde::Deserialize::deserialize(deserializer)?;
     match s.trim() {
         "" => Ok(None),
-        "Shield" => Ok(Some(UnitKind::Shield)),
-        "Spear" => Ok(Some(UnitKind::Spear)),
-        "Archer" => Ok(Some(UnitKind::Archer)),
-        "Cavalry" => Ok(Some(UnitKind::Cavalry)),
-        other => Err(serde::de::Error::unknown_variant(
-            other,
-            &["Shield", "Spear", "Archer", "Cavalry"],
-        )),
+        id => Ok(Some(UnitKind::new(id))),
     }
 }
 
+fn deserialize_buffs<'de, D>(deserializer: D) -> Result<Vec<EffectSpec>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    s.split(';')
+        .map(str::trim)
+        .filter(|spec| !spec.is_empty())
+        .map(|spec| {
+            EffectSpec::from_spec(spec)
+                .ok_or_else(|| serde::de::Error::custom(format!("bad effect `{spec}`")))
+        })
+        .collect()
+}
+
+fn deserialize_projectile<'de, D>(deserializer: D) -> Result<ProjectileSpec, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    ProjectileSpec::from_spec(&s)
+        .map_err(|part| serde::de::Error::unknown_variant(&part, ProjectileSpec::NAMES))
+}
+
 #[derive(Resource, Asset, Clone, TypePath)]
 pub(crate) struct UnitBalanceAssets {
     #[dependency]
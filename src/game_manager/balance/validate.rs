@@ -0,0 +1,554 @@
+//! Sanity checks for the balance sheets.
+//!
+//! The game re-runs them whenever a sheet or the LDtk project (re)loads; dev builds show the
+//! results on the loading screen. `check-balance` runs the same checks from the command line.
+
+use std::{collections::HashMap, fmt};
+
+use bevy_common_assets::csv::LoadedCsv;
+use bevy_ecs_ldtk::{assets::LdtkProject, ldtk::LdtkJson, prelude::LdtkFields};
+use serde::de::DeserializeOwned;
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<BalanceIssues>();
+    app.add_systems(
+        Update,
+        validate_balance.run_if(
+            resource_changed::<UnitStatsCache>
+                .or(resource_changed::<MemoryStatsCache>)
+                .or(resource_changed::<EffectRegistry>)
+                .or(on_message::<AssetEvent<LdtkProject>>),
+        ),
+    );
+}
+
+pub const UNIT_SHEET: &str = "all.unit.csv";
+pub const MEMORY_SHEET: &str = "all.memory.csv";
+pub const EFFECT_SHEET: &str = "all.effect.csv";
+
+// ============================================================================
+// Issues
+// ============================================================================
+
+/// One problem found in a balance sheet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceIssue {
+    pub sheet: &'static str,
+    /// Line in the sheet, counting the header as line 1; `None` when the row is missing.
+    pub line: Option<usize>,
+    pub id: String,
+    /// Header of the offending column, or empty when the whole row is at fault.
+    pub column: String,
+    pub message: String,
+}
+
+impl fmt::Display for BalanceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sheet)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        if !self.id.is_empty() {
+            write!(f, " [{}]", self.id)?;
+        }
+        if !self.column.is_empty() {
+            write!(f, " {}:", self.column)?;
+        }
+        write!(f, " {}", self.message)
+    }
+}
+
+/// Issues found by the last validation pass, empty when every sheet checks out.
+#[derive(Resource, Debug, Default)]
+pub struct BalanceIssues(pub Vec<BalanceIssue>);
+
+/// A place outside the sheet that names one of its rows.
+#[derive(Debug, Clone)]
+pub struct SheetReference {
+    pub id: String,
+    pub source: String,
+}
+
+// ============================================================================
+// Checks
+// ============================================================================
+
+/// Parses a sheet row by row, so one bad cell doesn't hide the rest of the file.
+///
+/// Returns every row that parsed, with its line number, and an issue per row that didn't.
+pub fn parse_sheet<R: DeserializeOwned>(
+    sheet: &'static str,
+    source: &str,
+) -> (Vec<(usize, R)>, Vec<BalanceIssue>) {
+    let mut reader = csv::Reader::from_reader(source.as_bytes());
+    let mut rows = Vec::new();
+    let mut issues = Vec::new();
+
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(error) => {
+            issues.push(BalanceIssue {
+                sheet,
+                line: Some(1),
+                id: String::new(),
+                column: String::new(),
+                message: error.to_string(),
+            });
+            return (rows, issues);
+        }
+    };
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                issues.push(BalanceIssue {
+                    sheet,
+                    line: error.position().map(|position| position.line() as usize),
+                    id: String::new(),
+                    column: String::new(),
+                    message: error.to_string(),
+                });
+                continue;
+            }
+        };
+        let line = record
+            .position()
+            .map_or(0, |position| position.line() as usize);
+
+        match record.deserialize::<R>(Some(&headers)) {
+            Ok(row) => rows.push((line, row)),
+            Err(error) => {
+                let (column, message) = match error.kind() {
+                    csv::ErrorKind::Deserialize { err, .. } => (
+                        err.field()
+                            .and_then(|field| headers.get(field as usize))
+                            .unwrap_or_default()
+                            .to_string(),
+                        err.kind().to_string(),
+                    ),
+                    _ => (String::new(), error.to_string()),
+                };
+                issues.push(BalanceIssue {
+                    sheet,
+                    line: Some(line),
+                    id: record.get(0).unwrap_or_default().to_string(),
+                    column,
+                    message,
+                });
+            }
+        }
+    }
+
+    (rows, issues)
+}
+
+/// Checks unit rows for duplicate ids, out-of-range stats and dangling unit kinds.
+pub fn validate_units<'a>(
+    rows: impl IntoIterator<Item = (usize, &'a UnitRow)>,
+    references: &[SheetReference],
+) -> Vec<BalanceIssue> {
+    let rows: Vec<_> = rows.into_iter().collect();
+    let ids = first_lines(rows.iter().map(|(line, row)| (*line, row.id.as_str())));
+    let mut issues = duplicate_ids(
+        UNIT_SHEET,
+        &ids,
+        rows.iter().map(|(line, row)| (*line, row.id.as_str())),
+    );
+
+    for &(line, row) in &rows {
+        let mut issue = |column: &str, message: String| {
+            issues.push(BalanceIssue {
+                sheet: UNIT_SHEET,
+                line: Some(line),
+                id: row.id.clone(),
+                column: column.to_string(),
+                message,
+            });
+        };
+
+        for (column, value) in [("hp", row.hp), ("atk_speed", row.atk_speed)] {
+            if value <= 0.0 {
+                issue(column, format!("must be positive, got {value}"));
+            }
+        }
+        for (column, value) in [
+            ("atk", row.atk),
+            ("def", row.def),
+            ("move_speed", row.move_speed),
+            ("range", row.range),
+            ("weight", row.weight),
+            ("cost", row.cost as f32),
+        ] {
+            if value < 0.0 {
+                issue(column, format!("must not be negative, got {value}"));
+            }
+        }
+        for (column, value) in [
+            ("res_slash", row.res_slash),
+            ("res_pierce", row.res_pierce),
+            ("res_blunt", row.res_blunt),
+            ("res_dream", row.res_dream),
+        ] {
+            if value > 1.0 {
+                issue(column, format!("must be at most 1 (immune), got {value}"));
+            }
+        }
+        for (column, value) in [("charge", row.charge), ("brace", row.brace)] {
+            if let Some(value) = value.filter(|value| *value <= 0.0) {
+                issue(column, format!("must be positive or empty, got {value}"));
+            }
+        }
+
+        let unknown_counter = row
+            .counter
+            .as_ref()
+            .filter(|counter| !ids.contains_key(counter.as_ref()));
+        if let Some(counter) = unknown_counter {
+            issue("counter", format!("unknown unit kind `{counter}`"));
+        }
+        if !ids.contains_key(row.unity_type.as_ref()) {
+            issue(
+                "unity_type",
+                format!("unknown unit kind `{}`", row.unity_type),
+            );
+        }
+    }
+
+    issues.extend(missing_references(UNIT_SHEET, &ids, references));
+    issues
+}
+
+/// Checks memory rows for duplicate ids, negative prices and missing memories.
+pub fn validate_memories<'a>(
+    rows: impl IntoIterator<Item = (usize, &'a MemoryRow)>,
+    references: &[SheetReference],
+) -> Vec<BalanceIssue> {
+    let rows: Vec<_> = rows.into_iter().collect();
+    let ids = first_lines(rows.iter().map(|(line, row)| (*line, row.id.as_str())));
+    let mut issues = duplicate_ids(
+        MEMORY_SHEET,
+        &ids,
+        rows.iter().map(|(line, row)| (*line, row.id.as_str())),
+    );
+
+    for &(line, row) in &rows {
+        if row.price < 0 {
+            issues.push(BalanceIssue {
+                sheet: MEMORY_SHEET,
+                line: Some(line),
+                id: row.id.clone(),
+                column: "price".to_string(),
+                message: format!("must not be negative, got {}", row.price),
+            });
+        }
+    }
+
+    issues.extend(missing_references(MEMORY_SHEET, &ids, references));
+    issues
+}
+
+/// Checks effect rows for duplicate ids, out-of-range numbers and effects used but not defined.
+pub fn validate_effects<'a>(
+    rows: impl IntoIterator<Item = (usize, &'a EffectRow)>,
+    references: &[SheetReference],
+) -> Vec<BalanceIssue> {
+    let rows: Vec<_> = rows.into_iter().collect();
+    let ids = first_lines(rows.iter().map(|(line, row)| (*line, row.id.as_str())));
+    let mut issues = duplicate_ids(
+        EFFECT_SHEET,
+        &ids,
+        rows.iter().map(|(line, row)| (*line, row.id.as_str())),
+    );
+
+    for &(line, row) in &rows {
+        let mut issue = |column: &str, message: String| {
+            issues.push(BalanceIssue {
+                sheet: EFFECT_SHEET,
+                line: Some(line),
+                id: row.id.clone(),
+                column: column.to_string(),
+                message,
+            });
+        };
+
+        for (column, value) in [
+            ("regen", row.regen),
+            ("duration", row.duration),
+            ("tick", row.tick),
+        ] {
+            if let Some(value) = value.filter(|value| *value <= 0.0) {
+                issue(column, format!("must be positive, got {value}"));
+            }
+        }
+        for (column, value) in [("chance", row.chance), ("on_hit_damage", row.on_hit_damage)] {
+            if let Some(value) = value.filter(|value| *value < 0.0) {
+                issue(column, format!("must not be negative, got {value}"));
+            }
+        }
+    }
+
+    issues.extend(missing_references(EFFECT_SHEET, &ids, references));
+    issues
+}
+
+/// Effect ids that units start with or that other effects apply or spread.
+pub fn effect_references<'a>(
+    units: impl IntoIterator<Item = &'a UnitRow>,
+    effects: impl IntoIterator<Item = &'a EffectRow>,
+) -> Vec<SheetReference> {
+    let from_units = units.into_iter().flat_map(|unit| {
+        unit.buffs.iter().map(|buff| SheetReference {
+            id: buff.id.to_string(),
+            source: format!("unit {}", unit.id),
+        })
+    });
+    let from_effects = effects.into_iter().flat_map(|effect| {
+        let on_attack = effect.on_attack.as_ref().map(|on_attack| SheetReference {
+            id: on_attack.id.to_string(),
+            source: format!("on_attack of effect {}", effect.id),
+        });
+        let aura = effect.aura.as_ref().map(|aura| SheetReference {
+            id: aura.id.to_string(),
+            source: format!("aura of effect {}", effect.id),
+        });
+        on_attack.into_iter().chain(aura)
+    });
+    from_units.chain(from_effects).collect()
+}
+
+/// Unit ids that enemy squads in the LDtk project spawn.
+pub fn ldtk_unit_references(project: &LdtkJson) -> Vec<SheetReference> {
+    let mut references = Vec::new();
+    for level in &project.levels {
+        let entities = level
+            .layer_instances
+            .iter()
+            .flatten()
+            .flat_map(|layer| &layer.entity_instances)
+            .filter(|entity| entity.identifier == "EnemySquad");
+        for entity in entities {
+            if let Ok(prefab) = entity.get_string_field("unit_prefab") {
+                references.push(SheetReference {
+                    id: prefab.clone(),
+                    source: format!("EnemySquad in level {}", level.identifier),
+                });
+            }
+        }
+    }
+    references
+}
+
+/// Memory ids the game looks up by name.
+pub fn memory_references() -> Vec<SheetReference> {
+    MemoryKind::ALL
+        .into_iter()
+        .map(|memory| SheetReference {
+            id: memory.id().to_string(),
+            source: format!("MemoryKind::{memory:?}"),
+        })
+        .collect()
+}
+
+/// Maps every id to the first line it appears on.
+fn first_lines<'a>(rows: impl Iterator<Item = (usize, &'a str)>) -> HashMap<&'a str, usize> {
+    let mut ids = HashMap::new();
+    for (line, id) in rows {
+        ids.entry(id).or_insert(line);
+    }
+    ids
+}
+
+fn duplicate_ids<'a>(
+    sheet: &'static str,
+    ids: &HashMap<&str, usize>,
+    rows: impl Iterator<Item = (usize, &'a str)>,
+) -> Vec<BalanceIssue> {
+    let mut issues = Vec::new();
+    for (line, id) in rows {
+        let message = if id.trim().is_empty() {
+            "must not be empty".to_string()
+        } else if ids[id] != line {
+            format!("duplicate id, first defined on line {}", ids[id])
+        } else {
+            continue;
+        };
+        issues.push(BalanceIssue {
+            sheet,
+            line: Some(line),
+            id: id.to_string(),
+            column: "id".to_string(),
+            message,
+        });
+    }
+    issues
+}
+
+fn missing_references(
+    sheet: &'static str,
+    ids: &HashMap<&str, usize>,
+    references: &[SheetReference],
+) -> Vec<BalanceIssue> {
+    references
+        .iter()
+        .filter(|reference| !ids.contains_key(reference.id.as_str()))
+        .map(|reference| BalanceIssue {
+            sheet,
+            line: None,
+            id: reference.id.clone(),
+            column: String::new(),
+            message: format!("no row, but {} uses it", reference.source),
+        })
+        .collect()
+}
+
+/// Numbers loaded rows by line, assuming one line per row after the header.
+fn numbered<R>(rows: &[R]) -> impl Iterator<Item = (usize, &R)> {
+    rows.iter().enumerate().map(|(index, row)| (index + 2, row))
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn validate_balance(
+    unit_assets: Option<Res<UnitBalanceAssets>>,
+    memory_assets: Option<Res<MemoryBalanceAssets>>,
+    effect_assets: Option<Res<EffectBalanceAssets>>,
+    scene_assets: Option<Res<SceneAssets>>,
+    unit_sheets: Res<Assets<LoadedCsv<UnitRow>>>,
+    memory_sheets: Res<Assets<LoadedCsv<MemoryRow>>>,
+    effect_sheets: Res<Assets<LoadedCsv<EffectRow>>>,
+    ldtk_projects: Res<Assets<LdtkProject>>,
+    mut balance_issues: ResMut<BalanceIssues>,
+) {
+    let mut issues = Vec::new();
+
+    let unit_sheet = unit_assets.and_then(|assets| unit_sheets.get(&assets.units));
+    if let Some(unit_sheet) = unit_sheet {
+        let references = scene_assets
+            .and_then(|assets| ldtk_projects.get(&assets.level))
+            .map(|project| ldtk_unit_references(project.json_data()))
+            .unwrap_or_default();
+        issues.extend(validate_units(numbered(&unit_sheet.rows), &references));
+    }
+
+    let effect_sheet = effect_assets.and_then(|assets| effect_sheets.get(&assets.effects));
+    if let Some(effect_sheet) = effect_sheet {
+        let units = unit_sheet
+            .map(|sheet| sheet.rows.as_slice())
+            .unwrap_or_default();
+        issues.extend(validate_effects(
+            numbered(&effect_sheet.rows),
+            &effect_references(units, &effect_sheet.rows),
+        ));
+    }
+
+    let memory_sheet = memory_assets.and_then(|assets| memory_sheets.get(&assets.memories));
+    if let Some(memory_sheet) = memory_sheet {
+        issues.extend(validate_memories(
+            numbered(&memory_sheet.rows),
+            &memory_references(),
+        ));
+    }
+
+    for issue in &issues {
+        error!("[Balance] {issue}");
+    }
+    if balance_issues.0 != issues {
+        balance_issues.0 = issues;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNITS: &str = include_str!("../../../assets/balance/all.unit.csv");
+    const MEMORIES: &str = include_str!("../../../assets/balance/all.memory.csv");
+    const EFFECTS: &str = include_str!("../../../assets/balance/all.effect.csv");
+
+    fn check_units(source: &str) -> Vec<BalanceIssue> {
+        let (rows, mut issues) = parse_sheet::<UnitRow>(UNIT_SHEET, source);
+        issues.extend(validate_units(
+            rows.iter().map(|(line, row)| (*line, row)),
+            &[],
+        ));
+        issues
+    }
+
+    #[test]
+    fn shipped_sheets_have_no_issues() {
+        let level: LdtkJson =
+            serde_json::from_str(include_str!("../../../assets/chaos_dream.ldtk")).unwrap();
+        let (units, mut issues) = parse_sheet::<UnitRow>(UNIT_SHEET, UNITS);
+        let (memories, memory_issues) = parse_sheet::<MemoryRow>(MEMORY_SHEET, MEMORIES);
+        let (effects, effect_issues) = parse_sheet::<EffectRow>(EFFECT_SHEET, EFFECTS);
+        issues.extend(memory_issues);
+        issues.extend(effect_issues);
+        issues.extend(validate_units(
+            units.iter().map(|(line, row)| (*line, row)),
+            &ldtk_unit_references(&level),
+        ));
+        issues.extend(validate_effects(
+            effects.iter().map(|(line, row)| (*line, row)),
+            &effect_references(
+                units.iter().map(|(_, row)| row),
+                effects.iter().map(|(_, row)| row),
+            ),
+        ));
+        issues.extend(validate_memories(
+            memories.iter().map(|(line, row)| (*line, row)),
+            &memory_references(),
+        ));
+
+        assert_eq!(issues, Vec::new());
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let source = UNITS.replacen("Counter Rage,Archer,", "Counter Rage,Archr,", 1);
+
+        let issues = check_units(&source);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[0].id, "Shield");
+        assert_eq!(issues[0].column, "counter");
+    }
+
+    #[test]
+    fn bad_cell_does_not_hide_other_rows() {
+        let source = UNITS.replacen("Cavalry,60.0,", "Cavalry,sixty,", 1);
+        let duplicate = UNITS.lines().nth(1).unwrap();
+        let source = format!("{source}{duplicate}\n");
+
+        let issues = check_units(&source);
+
+        let columns: Vec<_> = issues.iter().map(|issue| issue.column.as_str()).collect();
+        assert_eq!(columns, ["hp", "id"]);
+        // The duplicate is appended after the last row
+        assert_eq!(issues[1].line, Some(UNITS.lines().count() + 1));
+    }
+
+    #[test]
+    fn unknown_buff_is_reported() {
+        let source = UNITS.replacen(",Block,", ",Blok,", 1);
+        let (units, _) = parse_sheet::<UnitRow>(UNIT_SHEET, &source);
+        let (effects, _) = parse_sheet::<EffectRow>(EFFECT_SHEET, EFFECTS);
+
+        let issues = validate_effects(
+            effects.iter().map(|(line, row)| (*line, row)),
+            &effect_references(
+                units.iter().map(|(_, row)| row),
+                effects.iter().map(|(_, row)| row),
+            ),
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "Blok");
+        assert_eq!(issues[0].line, None);
+    }
+}
@@ -0,0 +1,232 @@
+//! The campaign: which nights are fought, in what order, and with what.
+//!
+//! Read from `campaigns/main.campaign.ron`, or the file named by the `CAMPAIGN` environment
+//! variable, so nights can be added, reordered or swapped for another campaign without code.
+
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+
+use crate::{asset_tracking::LoadResource, prelude::*, screens::loading::LoadingScreen};
+
+/// The campaign as shipped, for tools and tests that run without an `AssetServer`.
+const SHIPPED_CAMPAIGN: &str = include_str!("../../../assets/campaigns/main.campaign.ron");
+
+/// Campaign loaded unless `CAMPAIGN` names another one.
+const DEFAULT_CAMPAIGN_PATH: &str = "campaigns/main.campaign.ron";
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_asset::<Campaign>();
+    app.init_asset_loader::<CampaignLoader>();
+    app.init_resource::<Campaign>();
+    app.add_systems(OnEnter(LoadingScreen::Level), apply_loaded_campaign);
+    app.add_systems(
+        Update,
+        reload_campaign.run_if(resource_exists::<CampaignAssets>),
+    );
+
+    app.load_resource::<CampaignAssets>();
+}
+
+// ============================================================================
+// Campaign data
+// ============================================================================
+
+/// Every night of a run, fought in order.
+#[derive(Resource, Asset, TypePath, serde::Deserialize, Debug, Clone)]
+pub struct Campaign {
+    /// Defeats that end the run.
+    pub max_losses: u32,
+    pub nights: Vec<Night>,
+}
+
+/// Starts out with the shipped campaign; the loaded asset replaces it once it's in.
+impl Default for Campaign {
+    fn default() -> Self {
+        Self::from_ron_str(SHIPPED_CAMPAIGN).expect("shipped main.campaign.ron should parse")
+    }
+}
+
+impl Campaign {
+    pub fn from_ron_str(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// The night of `round`, counted from 1 like [`GameProgress::current_round`].
+    pub fn night(&self, round: usize) -> Option<&Night> {
+        self.nights.get(round.checked_sub(1)?)
+    }
+}
+
+/// One night of the campaign.
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Night {
+    /// Identifier of the night's level in the LDtk project.
+    pub level: String,
+    pub gold: NightGold,
+    /// Camera zoom while the night is on.
+    pub zoom: f32,
+    /// Battle music: a track loaded up front, or any `audio/music/<music>.ogg`.
+    #[serde(default = "default_music")]
+    pub music: String,
+    /// Unit sheet ids the shop sells.
+    pub units: Vec<String>,
+    /// Memories the shop sells.
+    #[serde(default)]
+    pub memories: Vec<MemoryKind>,
+    #[serde(default)]
+    pub victory: Victory,
+}
+
+fn default_music() -> String {
+    "battle".to_string()
+}
+
+impl Night {
+    pub fn offers(&self, memory: MemoryKind) -> bool {
+        self.memories.contains(&memory)
+    }
+}
+
+/// The gold a night hands the player.
+#[derive(serde::Deserialize, Debug, Clone, Copy)]
+pub enum NightGold {
+    /// Exactly this much, whatever was left from the night before.
+    Starting(u32),
+    /// This much on top of what was left.
+    Bonus(u32),
+}
+
+impl NightGold {
+    pub fn apply(self, player_gold: &mut PlayerGold) {
+        match self {
+            NightGold::Starting(amount) => player_gold.amount = amount,
+            NightGold::Bonus(amount) => {
+                player_gold.amount = player_gold.amount.saturating_add(amount);
+            }
+        }
+    }
+}
+
+/// What the player has to do to win a night. Losing every unit always loses it.
+#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub enum Victory {
+    /// Wipe out every enemy unit.
+    #[default]
+    Rout,
+    /// Have units standing after this many seconds, or wipe out the enemy sooner.
+    Survive { seconds: f32 },
+}
+
+impl Victory {
+    pub fn is_won(self, player_count: usize, enemy_count: usize, elapsed_secs: f32) -> bool {
+        match self {
+            Victory::Rout => enemy_count == 0,
+            Victory::Survive { seconds } => {
+                enemy_count == 0 || (player_count > 0 && elapsed_secs >= seconds)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+#[derive(Default, TypePath)]
+struct CampaignLoader;
+
+impl AssetLoader for CampaignLoader {
+    type Asset = Campaign;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Campaign, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["campaign.ron"]
+    }
+}
+
+#[derive(Resource, Asset, Clone, TypePath)]
+pub(crate) struct CampaignAssets {
+    #[dependency]
+    pub(crate) campaign: Handle<Campaign>,
+}
+
+impl FromWorld for CampaignAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        let path = std::env::var("CAMPAIGN").unwrap_or_else(|_| DEFAULT_CAMPAIGN_PATH.to_string());
+
+        Self {
+            campaign: assets.load(path),
+        }
+    }
+}
+
+fn apply_loaded_campaign(
+    mut campaign: ResMut<Campaign>,
+    campaign_assets: Res<CampaignAssets>,
+    loaded: Res<Assets<Campaign>>,
+) {
+    let Some(loaded) = loaded.get(&campaign_assets.campaign) else {
+        warn!("Campaign not loaded yet, keeping the shipped one");
+        return;
+    };
+    if loaded.nights.is_empty() {
+        warn!("Campaign has no nights, keeping the current one");
+        return;
+    }
+
+    *campaign = loaded.clone();
+    info!("Loaded campaign with {} nights", campaign.nights.len());
+}
+
+/// Picks up edits to the campaign file while the game runs.
+fn reload_campaign(
+    mut asset_events: MessageReader<AssetEvent<Campaign>>,
+    campaign: ResMut<Campaign>,
+    campaign_assets: Res<CampaignAssets>,
+    loaded: Res<Assets<Campaign>>,
+) {
+    let modified = asset_events
+        .read()
+        .any(|event| event.is_modified(&campaign_assets.campaign));
+    if modified {
+        apply_loaded_campaign(campaign, campaign_assets, loaded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_campaign_parses() {
+        let campaign = Campaign::default();
+        assert_eq!(campaign.nights.len(), 3);
+        assert_eq!(
+            campaign.night(1).map(|night| night.level.as_str()),
+            Some("Level1")
+        );
+        assert!(campaign.night(0).is_none());
+        assert!(campaign.night(4).is_none());
+    }
+
+    #[test]
+    fn surviving_wins_once_time_is_up() {
+        let victory = Victory::Survive { seconds: 60.0 };
+        assert!(!victory.is_won(10, 5, 30.0));
+        assert!(victory.is_won(10, 5, 60.0));
+        assert!(!victory.is_won(0, 5, 60.0));
+        assert!(!Victory::Rout.is_won(10, 5, 60.0));
+    }
+}
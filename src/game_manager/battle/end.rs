@@ -13,17 +13,12 @@ pub(super) fn plugin(app: &mut App) {
     );
     app.add_systems(
         Update,
-        click_to_continue.run_if(
-            in_state(GameState::WinAndNextDay)
-                .and(|progress: Res<GameProgress>| progress.current_round < 4),
-        ),
+        click_to_continue.run_if(in_state(GameState::WinAndNextDay).and(campaign_continues)),
     );
     app.add_systems(
         Update,
-        click_to_leaderboard.run_if(
-            in_state(GameState::WinAndNextDay)
-                .and(|progress: Res<GameProgress>| progress.current_round >= 4),
-        ),
+        click_to_leaderboard
+            .run_if(in_state(GameState::WinAndNextDay).and(not(campaign_continues))),
     );
     app.add_systems(
         Update,
@@ -34,11 +29,23 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         click_to_title_from_leaderboard.run_if(in_state(GameState::Leaderboard)),
     );
-    app.add_systems(OnExit(GameState::WinAndNextDay), despawn_all_pawns);
+    app.add_systems(
+        OnExit(GameState::WinAndNextDay),
+        (despawn_enemies, carry_over_survivors).run_if(campaign_continues),
+    );
+    app.add_systems(
+        OnExit(GameState::WinAndNextDay),
+        despawn_all_pawns.run_if(not(campaign_continues)),
+    );
     app.add_systems(OnExit(GameState::Lose), despawn_all_pawns);
     app.add_systems(OnEnter(GameState::Preparing), trigger_fade_in_on_prepare);
 }
 
+/// Whether another night follows the one just won.
+fn campaign_continues(progress: Res<GameProgress>, campaign: Res<Campaign>) -> bool {
+    campaign.night(progress.current_round).is_some()
+}
+
 fn click_to_continue(
     mouse: Res<ButtonInput<MouseButton>>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -76,6 +83,7 @@ fn click_to_title_from_leaderboard(
     mouse: Res<ButtonInput<MouseButton>>,
     mut next_screen: ResMut<NextState<Screen>>,
     mut progress: ResMut<GameProgress>,
+    campaign: Res<Campaign>,
     time: Res<Time>,
     mut timer: ResMut<LeaderboardTimer>,
     _commands: Commands,
@@ -85,7 +93,7 @@ fn click_to_title_from_leaderboard(
 
     // Only allow transition after 3 seconds have elapsed AND mouse is clicked
     if timer.0.elapsed_secs() >= 3.0 && mouse.just_pressed(MouseButton::Left) {
-        progress.current_round = 3;
+        progress.current_round = campaign.nights.len();
         next_screen.set(Screen::Title);
     }
 }
@@ -94,7 +102,10 @@ fn check_battle_result(
     q_player_units: Query<(), With<PlayerFaction>>,
     q_enemy_units: Query<(), With<EnemyFaction>>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut campaign: ResMut<GameProgress>,
+    mut progress: ResMut<GameProgress>,
+    campaign: Res<Campaign>,
+    battle_score: Res<BattleScore>,
+    time: Res<Time<Virtual>>,
     _commands: Commands,
 ) {
     let player_count = q_player_units.iter().count();
@@ -102,13 +113,19 @@ fn check_battle_result(
 
     info!("Battle check: {} player units, {} enemy units", player_count, enemy_count);
 
-    if enemy_count == 0 {
-        info!("All enemies defeated - Win!");
-        campaign.record_battle(true);
+    let victory = campaign
+        .night(progress.current_round)
+        .map(|night| night.victory)
+        .unwrap_or_default();
+    let elapsed = (time.elapsed_secs_f64() - battle_score.battle_start_time) as f32;
+
+    if victory.is_won(player_count, enemy_count, elapsed) {
+        info!("Night won ({:?}) - Win!", victory);
+        progress.record_battle(true, &campaign);
         next_state.set(GameState::WinAndNextDay);
     } else if player_count == 0 {
         info!("All player units lost - Lose!");
-        campaign.record_battle(false);
+        progress.record_battle(false, &campaign);
         next_state.set(GameState::Lose);
     }
 }
@@ -130,6 +147,61 @@ fn despawn_all_pawns(
     }
 }
 
+/// Clears the enemy and the fallen once a night is won; the player's army stays.
+fn despawn_enemies(
+    mut commands: Commands,
+    q_enemies: Query<Entity, With<EnemySquad>>,
+    q_corpses: Query<Entity, With<Corpse>>,
+) {
+    for entity in q_enemies.iter().chain(q_corpses.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Surviving player squads carry over to the next night: their units re-form at full health
+/// where the squad started the battle, but the fallen stay lost. Wiped-out squads are gone.
+fn carry_over_survivors(
+    mut commands: Commands,
+    unit_stats: Res<UnitStatsCache>,
+    q_squads: Query<(Entity, &Squad, &Faction, &RootStationSquad), With<PlayerSquad>>,
+) {
+    for (squad_entity, squad, faction, units) in &q_squads {
+        if squad.current_unit_count == 0 {
+            commands.entity(squad_entity).despawn();
+            continue;
+        }
+        let Some(definition) = unit_stats.stats.get(&squad.child_prefab_name) else {
+            commands.entity(squad_entity).despawn();
+            continue;
+        };
+
+        for &unit in units.iter() {
+            commands.entity(unit).despawn();
+        }
+        let survivors = spawn_units_in_formation(
+            &mut commands,
+            0..squad.current_unit_count,
+            squad,
+            definition,
+            *faction,
+            squad_entity,
+        );
+        commands
+            .entity(squad_entity)
+            .add_children(&survivors)
+            .insert((
+                SquadMorale::default(),
+                SquadHitCount::default(),
+                SquadTakeHitCount::default(),
+            ));
+
+        info!(
+            "Squad {:?} carries over with {}/{} {} units",
+            squad_entity, squad.current_unit_count, squad.max_unit_count, squad.child_prefab_name
+        );
+    }
+}
+
 /// Triggers camera fade out when entering GameState::Preparing
 fn trigger_fade_in_on_prepare(mut commands: Commands) {
     commands.trigger(FadeInEvent::default());
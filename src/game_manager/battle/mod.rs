@@ -6,9 +6,25 @@ pub(crate) use status::{BattleStatus, BattleStatusType};
 mod progress;
 pub(crate) use progress::GameProgress;
 
+mod campaign;
+pub(crate) use campaign::Campaign;
+
+mod replay;
+pub(crate) use replay::{BattleReplay, Battleground, load_replay};
+
+mod save;
+pub(crate) use save::SavedRun;
+
+mod undo;
+pub(crate) use undo::PlacementChangedMessage;
+
 use bevy::prelude::*;
 pub(crate) fn plugin(app: &mut App) {
     app.add_plugins(end::plugin);
     app.add_plugins(status::plugin);
     app.add_plugins(progress::plugin);
+    app.add_plugins(campaign::plugin);
+    app.add_plugins(replay::plugin);
+    app.add_plugins(save::plugin);
+    app.add_plugins(undo::plugin);
 }
@@ -7,7 +7,7 @@ pub(crate) fn plugin(app: &mut App) {
     app.register_type::<GameProgress>();
 }
 
-#[derive(Resource, Debug, Default, Reflect)]
+#[derive(Resource, Debug, Default, Clone, Reflect, serde::Serialize, serde::Deserialize)]
 pub struct GameProgress {
     pub history: Vec<BattleStatusType>,
     pub current_round: usize,
@@ -18,19 +18,20 @@ pub struct GameProgress {
 impl GameProgress {
     pub fn new() -> Self {
         Self {
-            history: vec![BattleStatusType::Pending; 15],
+            history: Vec::new(),
             current_round: 1,
             total_wins: 0,
             total_losses: 0,
         }
     }
 
-    pub fn is_game_over(&self) -> bool {
-        self.total_losses >= 3 || self.total_wins >= 10 || self.current_round >= 15
+    /// Every night of the campaign is won, or too many were lost.
+    pub fn is_game_over(&self, campaign: &Campaign) -> bool {
+        self.total_losses >= campaign.max_losses || campaign.night(self.current_round).is_none()
     }
 
-    pub fn record_battle(&mut self, is_victory: bool) {
-        if self.is_game_over() {
+    pub fn record_battle(&mut self, is_victory: bool, campaign: &Campaign) {
+        if self.is_game_over(campaign) {
             warn!("Game is already over!");
             return;
         }
@@ -43,16 +44,18 @@ impl GameProgress {
             BattleStatusType::Defeat
         };
 
-        if self.current_round > 0 && self.current_round <= self.history.len() {
-            self.history[self.current_round - 1] = status;
-            if is_victory {
-                self.current_round += 1;
-            }
+        if self.history.len() < self.current_round {
+            self.history
+                .resize(self.current_round, BattleStatusType::Pending);
+        }
+        self.history[self.current_round - 1] = status;
+        if is_victory {
+            self.current_round += 1;
         }
 
-        if self.total_wins >= 10 {
+        if campaign.night(self.current_round).is_none() {
             info!("Campaign Victory!");
-        } else if self.total_losses >= 3 {
+        } else if self.total_losses >= campaign.max_losses {
             info!("Campaign Defeat!");
         }
     }
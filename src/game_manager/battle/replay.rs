@@ -0,0 +1,320 @@
+use bevy_rand::{global::GlobalRng, prelude::ChaCha8Rng};
+use rand::Rng;
+
+use crate::prelude::*;
+
+/// Bumped whenever the log layout changes; older replays are rejected on load.
+const REPLAY_VERSION: u32 = 4;
+
+#[cfg(not(target_family = "wasm"))]
+const REPLAY_DIR: &str = "replays";
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<ReplayRecorder>();
+
+    app.add_systems(OnEnter(GameState::Battle), start_recording);
+    app.add_systems(Last, record_frame.run_if(in_state(GameState::Battle)));
+    app.add_systems(OnExit(GameState::Battle), finish_recording);
+}
+
+// ============================================================================
+// Replay log
+// ============================================================================
+
+/// Everything needed to re-simulate a battle frame-for-frame.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct BattleReplay {
+    pub version: u32,
+    pub round: usize,
+    /// Seed of the [`BattleRng`] for this battle.
+    pub seed: u64,
+    pub ground: Battleground,
+    pub squads: Vec<ReplaySquad>,
+    /// Real time of every battle frame, in nanoseconds.
+    pub frame_deltas_ns: Vec<u32>,
+    pub speed_changes: Vec<ReplaySpeedChange>,
+}
+
+/// The obstacles and terrain of the level the battle was fought on, so playback and the
+/// simulation fight on the same ground without loading the level.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct Battleground {
+    /// Corners of the level: `[min_x, min_y, max_x, max_y]`.
+    pub area: [f32; 4],
+    /// Centers of the cells units can't cross.
+    pub blocked: Vec<[f32; 2]>,
+    /// A corner of the terrain grid.
+    pub terrain_origin: [f32; 2],
+    /// Centers of the terrain cells, with their terrain.
+    pub terrain: Vec<([f32; 2], Terrain)>,
+}
+
+impl Battleground {
+    fn capture(nav: &NavGrid, terrain_map: &TerrainMap) -> Self {
+        let area = nav.area();
+        let mut blocked: Vec<[f32; 2]> = nav.blocked_cells().map(|cell| cell.to_array()).collect();
+        blocked.sort_by(|a, b| a[1].total_cmp(&b[1]).then(a[0].total_cmp(&b[0])));
+        let mut terrain: Vec<([f32; 2], Terrain)> = terrain_map
+            .cells()
+            .map(|(center, terrain)| (center.to_array(), terrain))
+            .collect();
+        terrain.sort_by(|(a, _), (b, _)| a[1].total_cmp(&b[1]).then(a[0].total_cmp(&b[0])));
+        Self {
+            area: [area.min.x, area.min.y, area.max.x, area.max.y],
+            blocked,
+            terrain_origin: terrain_map.origin().to_array(),
+            terrain,
+        }
+    }
+
+    /// Lays this ground out in `world`, in place of whatever level it had.
+    pub fn apply(&self, world: &mut World) {
+        let [min_x, min_y, max_x, max_y] = self.area;
+        world.resource_mut::<NavGrid>().rebuild(
+            Rect::new(min_x, min_y, max_x, max_y),
+            self.blocked.iter().copied().map(Vec2::from_array),
+        );
+        world.resource_mut::<TerrainMap>().rebuild(
+            Vec2::from_array(self.terrain_origin),
+            self.terrain
+                .iter()
+                .map(|&(center, terrain)| (Vec2::from_array(center), terrain)),
+        );
+    }
+}
+
+/// A squad as it stood when the battle started.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct ReplaySquad {
+    pub prefab: String,
+    pub max_unit_count: usize,
+    /// Units lost in earlier nights, out of `max_unit_count`.
+    #[serde(default)]
+    pub lost: usize,
+    pub position: [f32; 2],
+    pub faction: Faction,
+    pub memories: Vec<MemoryKind>,
+    #[serde(default)]
+    pub formation: Formation,
+    #[serde(default)]
+    pub stance: Stance,
+    /// Index into [`BattleReplay::squads`] of the squad a [`Stance::Focus`] squad goes for.
+    #[serde(default)]
+    pub focus: Option<usize>,
+    #[serde(default)]
+    pub veterancy: Veterancy,
+}
+
+/// A speed-button press, applied from `frame` onwards.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+pub struct ReplaySpeedChange {
+    pub frame: u32,
+    pub relative_speed: f32,
+}
+
+/// The battle being recorded, if any.
+#[derive(Resource, Default)]
+struct ReplayRecorder(Option<BattleReplay>);
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Picks this battle's seed, reseeds the [`BattleRng`] and snapshots the starting layout.
+fn start_recording(
+    mut commands: Commands,
+    mut global_rng: Single<&mut ChaCha8Rng, With<GlobalRng>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    progress: Res<GameProgress>,
+    nav: Res<NavGrid>,
+    terrain_map: Res<TerrainMap>,
+    time: Res<Time<Virtual>>,
+    q_squads: Query<(
+        Entity,
+        &Squad,
+        &Veterancy,
+        &Faction,
+        &GlobalTransform,
+        Has<BigEyeBuff>,
+        Has<GoldenHeartBuff>,
+    )>,
+) {
+    let seed = global_rng.random::<u64>();
+    commands.insert_resource(BattleRng::from_seed(seed));
+
+    // Logged in battle order, which playback spawns them in, rather than entity order
+    let mut squads: Vec<_> = q_squads.iter().collect();
+    squads.sort_by(|a, b| battle_order((a.1, a.3, a.4), (b.1, b.3, b.4)));
+    let squad_entities: Vec<Entity> = squads.iter().map(|(entity, ..)| *entity).collect();
+    let squads = squads
+        .into_iter()
+        .map(
+            |(_, squad, veterancy, faction, transform, big_eye, golden_heart)| {
+                // Squads may have been dragged since placement, so `SquadOriginPosition`
+                // can be stale; the transform is where the squad actually starts.
+                let position = transform.translation().truncate();
+
+                ReplaySquad {
+                    prefab: squad.child_prefab_name.clone(),
+                    max_unit_count: squad.max_unit_count,
+                    lost: squad
+                        .max_unit_count
+                        .saturating_sub(squad.current_unit_count),
+                    position: position.to_array(),
+                    faction: *faction,
+                    memories: MemoryKind::from_buffs(big_eye, golden_heart),
+                    formation: squad.formation,
+                    stance: squad.stance,
+                    focus: squad.focus.and_then(|focus| {
+                        squad_entities.iter().position(|&entity| entity == focus)
+                    }),
+                    veterancy: *veterancy,
+                }
+            },
+        )
+        .collect();
+
+    recorder.0 = Some(BattleReplay {
+        version: REPLAY_VERSION,
+        round: progress.current_round,
+        seed,
+        ground: Battleground::capture(&nav, &terrain_map),
+        squads,
+        frame_deltas_ns: Vec::new(),
+        speed_changes: vec![ReplaySpeedChange {
+            frame: 0,
+            relative_speed: time.relative_speed(),
+        }],
+    });
+    info!(
+        "[Replay] Recording round {} with seed {}",
+        progress.current_round, seed
+    );
+}
+
+/// Logs this frame's real delta, and any speed change that takes effect next frame.
+fn record_frame(
+    mut recorder: ResMut<ReplayRecorder>,
+    real_time: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
+) {
+    let Some(replay) = recorder.0.as_mut() else {
+        return;
+    };
+
+    let delta_ns = real_time.delta().as_nanos().min(u32::MAX as u128) as u32;
+    replay.frame_deltas_ns.push(delta_ns);
+
+    let speed = virtual_time.relative_speed();
+    let last_speed = replay
+        .speed_changes
+        .last()
+        .map(|change| change.relative_speed);
+    if last_speed != Some(speed) {
+        replay.speed_changes.push(ReplaySpeedChange {
+            frame: replay.frame_deltas_ns.len() as u32,
+            relative_speed: speed,
+        });
+    }
+}
+
+/// Saves the finished battle next to the game on native builds; web builds have nowhere to keep it.
+fn finish_recording(mut recorder: ResMut<ReplayRecorder>) {
+    let Some(replay) = recorder.0.take() else {
+        return;
+    };
+    info!(
+        "[Replay] Recorded round {}: {} frames",
+        replay.round,
+        replay.frame_deltas_ns.len()
+    );
+
+    #[cfg(not(target_family = "wasm"))]
+    if let Err(error) = save_replay(&replay) {
+        warn!("[Replay] Could not save replay: {error:#}");
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn save_replay(replay: &BattleReplay) -> anyhow::Result<()> {
+    std::fs::create_dir_all(REPLAY_DIR)?;
+    let path = format!("{REPLAY_DIR}/night_{}.json", replay.round);
+    std::fs::write(&path, serde_json::to_string(replay)?)?;
+    info!("[Replay] Saved {path}");
+    Ok(())
+}
+
+/// Reads a replay saved by [`save_replay`].
+pub(crate) fn load_replay(path: &str) -> anyhow::Result<BattleReplay> {
+    use anyhow::Context;
+
+    let source = std::fs::read_to_string(path).with_context(|| format!("reading {path}"))?;
+    let replay: BattleReplay =
+        serde_json::from_str(&source).with_context(|| format!("parsing {path}"))?;
+    if replay.version != REPLAY_VERSION {
+        anyhow::bail!(
+            "{path} is replay version {}, expected {REPLAY_VERSION}",
+            replay.version
+        );
+    }
+    Ok(replay)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_rand::prelude::EntropyPlugin;
+
+    use super::*;
+    use crate::game_manager::simulation::{
+        BattleSetup, SIMULATION_STEP, build_app, fight, replay_battle,
+    };
+
+    #[test]
+    fn replay_reaches_the_recorded_end() {
+        let stats =
+            UnitStatsCache::from_csv_str(include_str!("../../../assets/balance/all.unit.csv"))
+                .expect("all.unit.csv should parse");
+        let mut setup = BattleSetup::facing(
+            &[(UnitKind::new("Spear"), 20), (UnitKind::new("Archer"), 15)],
+            &[(UnitKind::new("Cavalry"), 20)],
+            3,
+        );
+        setup.player[0].lost = 6;
+        setup.player[1].formation = Formation::Line;
+        // A wall across the middle of the field, with gaps at both ends
+        setup.ground = Battleground {
+            area: [-1600.0, -1024.0, 1600.0, 1024.0],
+            blocked: (-5..5)
+                .map(|row| [32.0, row as f32 * NAV_CELL_SIZE + 32.0])
+                .collect(),
+            // Mud in front of the enemy
+            terrain_origin: [0.0, 0.0],
+            terrain: (-8..8)
+                .map(|row| ([608.0, row as f32 * 64.0 + 32.0], Terrain::Mud))
+                .collect(),
+        };
+
+        // Record with the game's recorder, in an app whose entities the replay won't share
+        let mut app = build_app(&setup, &stats);
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([9; 32]));
+        app.init_resource::<GameProgress>();
+        plugin(&mut app);
+        for _ in 0..17 {
+            app.world_mut().spawn_empty();
+        }
+        let max_frames = (setup.time_limit / SIMULATION_STEP).ceil() as u32;
+        let recorded = fight(&mut app, &setup, &stats, max_frames, |_, _| {});
+        let replay = app
+            .world()
+            .resource::<ReplayRecorder>()
+            .0
+            .clone()
+            .expect("the battle should have been recorded");
+
+        assert_ne!(replay.seed, setup.seed);
+        assert_eq!(replay.squads[0].lost, 6);
+        assert_eq!(replay.ground.blocked, setup.ground.blocked);
+        assert_eq!(replay.ground.terrain, setup.ground.terrain);
+        assert_eq!(replay_battle(&replay, &stats).unwrap(), recorded);
+    }
+}
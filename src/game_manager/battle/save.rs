@@ -0,0 +1,377 @@
+use crate::{prelude::*, storage};
+
+/// Storage key of the current run.
+const SAVE_KEY: &str = "run";
+
+/// Bumped whenever the save layout changes; older saves are ignored.
+const SAVE_VERSION: u32 = 1;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.insert_resource(SavedRun(load_run()));
+
+    app.add_systems(
+        Update,
+        restore_preparation
+            .run_if(in_state(GameState::Preparing).and(resource_exists::<PendingPreparation>)),
+    );
+    app.add_systems(
+        Update,
+        restore_army.run_if(in_state(GameState::Preparing).and(resource_exists::<PendingArmy>)),
+    );
+    app.add_systems(
+        Update,
+        restore_focus.run_if(in_state(GameState::Preparing).and(any_with_component::<SavedFocus>)),
+    );
+    app.add_systems(
+        PostUpdate,
+        save_preparation.run_if(
+            in_state(GameState::Preparing)
+                .and(not(resource_exists::<PendingPreparation>))
+                .and(
+                    resource_changed::<PlayerGold>
+                        .or(any_component_removed::<SelectSquad>)
+                        .or(any_match_filter::<
+                            Or<(
+                                Added<SquadOriginPosition>,
+                                Changed<Squad>,
+                                Added<BigEyeBuff>,
+                                Added<GoldenHeartBuff>,
+                            )>,
+                        >),
+                ),
+        ),
+    );
+    app.add_systems(OnEnter(GameState::WinAndNextDay), save_battle_result);
+    app.add_systems(OnEnter(GameState::Lose), save_battle_result);
+}
+
+// ============================================================================
+// Save data
+// ============================================================================
+
+/// A campaign run that can be picked up again after the app closes.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RunSave {
+    pub version: u32,
+    pub progress: GameProgress,
+    /// The night being prepared, or `None` between nights.
+    pub preparation: Option<SavedPreparation>,
+    /// Squads that survived the last night, placed again once the next one is prepared.
+    #[serde(default)]
+    pub army: Vec<SavedSquad>,
+}
+
+/// Gold and squads of a night that is still being prepared.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct SavedPreparation {
+    pub gold: u32,
+    pub squads: Vec<SavedSquad>,
+}
+
+/// A player squad placed during [`GameState::Preparing`].
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct SavedSquad {
+    pub prefab: String,
+    pub unit_count: usize,
+    /// Units lost in earlier nights, out of `unit_count`.
+    #[serde(default)]
+    pub lost: usize,
+    pub position: [f32; 2],
+    pub memories: Vec<MemoryKind>,
+    #[serde(default)]
+    pub formation: Formation,
+    #[serde(default)]
+    pub stance: Stance,
+    /// Position of the enemy squad a [`Stance::Focus`] squad goes for.
+    /// Enemy squads are respawned from LDtk, so their position is what identifies them.
+    #[serde(default)]
+    pub focus: Option<[f32; 2]>,
+    #[serde(default)]
+    pub veterancy: Veterancy,
+}
+
+impl SavedSquad {
+    fn new(
+        squad: &Squad,
+        veterancy: &Veterancy,
+        position: Vec2,
+        big_eye: bool,
+        golden_heart: bool,
+        focus: Option<Vec2>,
+    ) -> Self {
+        Self {
+            prefab: squad.child_prefab_name.clone(),
+            unit_count: squad.max_unit_count,
+            lost: squad
+                .max_unit_count
+                .saturating_sub(squad.current_unit_count),
+            position: position.to_array(),
+            memories: MemoryKind::from_buffs(big_eye, golden_heart),
+            formation: squad.formation,
+            stance: squad.stance,
+            focus: focus.map(Vec2::to_array),
+            veterancy: *veterancy,
+        }
+    }
+
+    /// Places the squad again with its survivors and memories.
+    pub(super) fn spawn(&self, commands: &mut Commands) {
+        let position = Vec2::from_array(self.position);
+        let squad = Squad::new(self.prefab.clone(), self.unit_count)
+            .with_formation(self.formation)
+            .with_stance(self.stance)
+            .with_survivors(self.unit_count.saturating_sub(self.lost));
+        let entity = spawn_player_squad(commands, squad, position);
+        let mut squad_commands = commands.entity(entity);
+        squad_commands.insert((SquadOriginPosition(position), self.veterancy));
+        if let Some(focus) = self.focus {
+            squad_commands.insert(SavedFocus(Vec2::from_array(focus)));
+        }
+        for memory in &self.memories {
+            memory.insert_buff(&mut squad_commands);
+        }
+    }
+}
+
+/// The player squads placed so far, as [`SavedPreparation::capture`] reads them.
+pub(super) type PlacedSquads<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Squad,
+        &'static Veterancy,
+        &'static Transform,
+        Has<BigEyeBuff>,
+        Has<GoldenHeartBuff>,
+        Option<&'static SavedFocus>,
+    ),
+    (With<PlayerSquad>, With<SquadOriginPosition>),
+>;
+
+impl SavedPreparation {
+    /// The night being prepared as it stands.
+    pub(super) fn capture(
+        gold: u32,
+        q_squads: &PlacedSquads,
+        q_enemy_squads: &Query<&GlobalTransform, With<EnemySquad>>,
+    ) -> Self {
+        let squads = q_squads
+            .iter()
+            .map(
+                |(squad, veterancy, transform, big_eye, golden_heart, saved_focus)| {
+                    // A focus not restored yet is kept as it was saved.
+                    let focus = saved_focus.map(|saved_focus| saved_focus.0).or_else(|| {
+                        let focus = q_enemy_squads.get(squad.focus?).ok()?;
+                        Some(focus.translation().truncate())
+                    });
+                    SavedSquad::new(
+                        squad,
+                        veterancy,
+                        transform.translation.truncate(),
+                        big_eye,
+                        golden_heart,
+                        focus,
+                    )
+                },
+            )
+            .collect();
+        Self { gold, squads }
+    }
+
+    /// Hands back the gold and places the squads, on top of whatever is already placed.
+    pub(super) fn place(&self, commands: &mut Commands, player_gold: &mut PlayerGold) {
+        player_gold.amount = self.gold;
+        for saved in &self.squads {
+            saved.spawn(commands);
+        }
+    }
+}
+
+/// The last saved run, shown as "Continue" on the title screen.
+#[derive(Resource, Debug, Default)]
+pub struct SavedRun(pub Option<RunSave>);
+
+impl SavedRun {
+    /// Restores the saved progress; the squads are placed once the night starts.
+    pub fn resume(&self, commands: &mut Commands, progress: &mut GameProgress) {
+        let Some(run) = &self.0 else {
+            return;
+        };
+        *progress = run.progress.clone();
+        if let Some(preparation) = &run.preparation {
+            commands.insert_resource(PendingPreparation(preparation.clone()));
+        } else if !run.army.is_empty() {
+            commands.insert_resource(PendingArmy(run.army.clone()));
+        }
+        info!("[Save] Resuming night {}", progress.current_round);
+    }
+
+    /// Forgets the saved run and deletes it from storage.
+    pub fn clear(&mut self) {
+        self.0 = None;
+        if let Err(error) = storage::remove(SAVE_KEY) {
+            warn!("[Save] Could not clear save: {error:#}");
+        }
+    }
+}
+
+/// Preparation waiting to be restored on the next [`GameState::Preparing`].
+#[derive(Resource)]
+struct PendingPreparation(SavedPreparation);
+
+/// Surviving squads waiting to be placed again on the next [`GameState::Preparing`].
+#[derive(Resource)]
+struct PendingArmy(Vec<SavedSquad>);
+
+/// A restored squad's focus, waiting for the enemy squad at this position to spawn.
+#[derive(Component)]
+pub(super) struct SavedFocus(Vec2);
+
+/// How close an enemy squad has to be to a saved focus position to be the one it means.
+const FOCUS_MATCH_DISTANCE: f32 = 1.0;
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Runs in `Update`, so the gold handed out by `switch_to_next_level` on entering
+/// [`GameState::Preparing`] is already in and gets replaced by the saved amount.
+fn restore_preparation(
+    mut commands: Commands,
+    pending: Res<PendingPreparation>,
+    mut player_gold: ResMut<PlayerGold>,
+) {
+    let preparation = &pending.0;
+    preparation.place(&mut commands, &mut player_gold);
+
+    info!(
+        "[Save] Restored {} squads and {} gold",
+        preparation.squads.len(),
+        preparation.gold
+    );
+    commands.remove_resource::<PendingPreparation>();
+}
+
+/// Places the squads that survived the night won before the app closed.
+fn restore_army(mut commands: Commands, pending: Res<PendingArmy>) {
+    for saved in &pending.0 {
+        saved.spawn(&mut commands);
+    }
+
+    info!("[Save] Restored {} surviving squads", pending.0.len());
+    commands.remove_resource::<PendingArmy>();
+}
+
+/// Points restored squads back at the enemy squads they focused, once the level has spawned them.
+fn restore_focus(
+    mut commands: Commands,
+    mut q_squads: Query<(Entity, &mut Squad, &SavedFocus), Without<EnemySquad>>,
+    q_enemy_squads: Query<(Entity, &GlobalTransform), With<EnemySquad>>,
+) {
+    for (entity, mut squad, saved_focus) in &mut q_squads {
+        let Some((focus, _)) = q_enemy_squads.iter().find(|(_, transform)| {
+            transform.translation().truncate().distance(saved_focus.0) <= FOCUS_MATCH_DISTANCE
+        }) else {
+            continue;
+        };
+        squad.focus = Some(focus);
+        commands.entity(entity).remove::<SavedFocus>();
+    }
+}
+
+/// Saves the night being prepared whenever the player buys, places, moves or re-forms something.
+fn save_preparation(
+    progress: Res<GameProgress>,
+    player_gold: Res<PlayerGold>,
+    q_squads: PlacedSquads,
+    q_enemy_squads: Query<&GlobalTransform, With<EnemySquad>>,
+    mut saved_run: ResMut<SavedRun>,
+) {
+    write_run(
+        &mut saved_run,
+        RunSave {
+            version: SAVE_VERSION,
+            progress: progress.clone(),
+            preparation: Some(SavedPreparation::capture(
+                player_gold.amount,
+                &q_squads,
+                &q_enemy_squads,
+            )),
+            army: Vec::new(),
+        },
+    );
+}
+
+/// Saves the outcome of the night and the squads that survived it,
+/// or clears the save once the campaign is over.
+fn save_battle_result(
+    progress: Res<GameProgress>,
+    campaign: Res<Campaign>,
+    q_squads: Query<
+        (
+            &Squad,
+            &Veterancy,
+            &SquadOriginPosition,
+            Has<BigEyeBuff>,
+            Has<GoldenHeartBuff>,
+        ),
+        With<PlayerSquad>,
+    >,
+    mut saved_run: ResMut<SavedRun>,
+) {
+    if progress.is_game_over(&campaign) {
+        saved_run.clear();
+        return;
+    }
+
+    write_run(
+        &mut saved_run,
+        RunSave {
+            version: SAVE_VERSION,
+            progress: progress.clone(),
+            preparation: None,
+            army: q_squads
+                .iter()
+                .filter(|(squad, ..)| squad.current_unit_count > 0)
+                .map(|(squad, veterancy, origin, big_eye, golden_heart)| {
+                    SavedSquad::new(squad, veterancy, origin.0, big_eye, golden_heart, None)
+                })
+                .collect(),
+        },
+    );
+}
+
+fn write_run(saved_run: &mut SavedRun, run: RunSave) {
+    let result = serde_json::to_string(&run)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| storage::write(SAVE_KEY, &json));
+    if let Err(error) = result {
+        warn!("[Save] Could not save run: {error:#}");
+    }
+    saved_run.0 = Some(run);
+}
+
+fn load_run() -> Option<RunSave> {
+    let json = match storage::read(SAVE_KEY) {
+        Ok(json) => json?,
+        Err(error) => {
+            warn!("[Save] Could not read save: {error:#}");
+            return None;
+        }
+    };
+
+    match serde_json::from_str::<RunSave>(&json) {
+        Ok(run) if run.version == SAVE_VERSION => Some(run),
+        Ok(run) => {
+            warn!(
+                "[Save] Ignoring save version {}, expected {SAVE_VERSION}",
+                run.version
+            );
+            None
+        }
+        Err(error) => {
+            warn!("[Save] Ignoring unreadable save: {error}");
+            None
+        }
+    }
+}
@@ -24,7 +24,18 @@ pub(crate) fn plugin(app: &mut App) {
     );
 }
 
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[derive(
+    Resource,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub enum BattleStatusType {
     #[default]
     Pending,
@@ -0,0 +1,129 @@
+//! Undo and redo of the placements made while preparing a night.
+//!
+//! Every [`PlacementChangedMessage`] snapshots the night as [`SavedPreparation`],
+//! so stepping back is placing an older snapshot again.
+
+use super::save::{PlacedSquads, SavedPreparation};
+use crate::prelude::*;
+
+/// Snapshots kept to step back through.
+const MAX_UNDO_STEPS: usize = 50;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<PlacementHistory>();
+    app.add_message::<PlacementChangedMessage>();
+
+    app.add_systems(
+        Update,
+        undo_redo_on_keys.run_if(in_state(GameState::Preparing)),
+    );
+    app.add_systems(
+        PostUpdate,
+        record_placement.run_if(in_state(GameState::Preparing)),
+    );
+    app.add_systems(OnExit(GameState::Preparing), clear_history);
+}
+
+/// A squad was bought, moved, re-formed, sold, merged, reinforced or given a memory.
+#[derive(Message, Debug, Clone)]
+pub struct PlacementChangedMessage;
+
+/// Snapshots of the night being prepared, to step back and forth between.
+#[derive(Resource, Default)]
+struct PlacementHistory {
+    undo: Vec<SavedPreparation>,
+    redo: Vec<SavedPreparation>,
+    /// What is placed right now, or `None` until the first snapshot.
+    current: Option<SavedPreparation>,
+}
+
+fn clear_history(mut history: ResMut<PlacementHistory>) {
+    *history = PlacementHistory::default();
+}
+
+/// Snapshots the night once on entering it, then after every change to the placements.
+fn record_placement(
+    mut placement_msg: MessageReader<PlacementChangedMessage>,
+    mut history: ResMut<PlacementHistory>,
+    player_gold: Res<PlayerGold>,
+    q_squads: PlacedSquads,
+    q_enemy_squads: Query<&GlobalTransform, With<EnemySquad>>,
+) {
+    let changed = placement_msg.read().count() > 0;
+    if !changed && history.current.is_some() {
+        return;
+    }
+
+    let snapshot = SavedPreparation::capture(player_gold.amount, &q_squads, &q_enemy_squads);
+    let Some(previous) = history.current.replace(snapshot) else {
+        return;
+    };
+    if history.current.as_ref() == Some(&previous) {
+        return;
+    }
+
+    history.undo.push(previous);
+    if history.undo.len() > MAX_UNDO_STEPS {
+        history.undo.remove(0);
+    }
+    history.redo.clear();
+}
+
+/// Ctrl+Z undoes the last placement, Ctrl+Y or Ctrl+Shift+Z redoes it.
+fn undo_redo_on_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut history: ResMut<PlacementHistory>,
+    mut player_gold: ResMut<PlayerGold>,
+    q_select_squad: Query<(), With<SelectSquad>>,
+    q_player_squads: Query<Entity, With<PlayerSquad>>,
+    mut commands: Commands,
+) {
+    let ctrl = keyboard.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    if !ctrl {
+        return;
+    }
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let undo = keyboard.just_pressed(KeyCode::KeyZ) && !shift;
+    let redo =
+        keyboard.just_pressed(KeyCode::KeyY) || (keyboard.just_pressed(KeyCode::KeyZ) && shift);
+    if !undo && !redo {
+        return;
+    }
+
+    // Nothing is undone while a squad or memory is still in hand
+    if mouse.pressed(MouseButton::Left) || !q_select_squad.is_empty() {
+        commands.trigger(SFXEvent::ui("invalid"));
+        return;
+    }
+
+    let history = &mut *history;
+    let (from, to) = if undo {
+        (&mut history.undo, &mut history.redo)
+    } else {
+        (&mut history.redo, &mut history.undo)
+    };
+    let Some(snapshot) = from.pop() else {
+        commands.trigger(SFXEvent::ui("invalid"));
+        return;
+    };
+    to.extend(history.current.replace(snapshot.clone()));
+
+    for squad in &q_player_squads {
+        commands.entity(squad).despawn();
+    }
+    snapshot.place(&mut commands, &mut player_gold);
+    commands.trigger(SFXEvent::ui("put"));
+
+    info!(
+        "[{}] Back to {} squads and {} gold",
+        if undo { "Undo" } else { "Redo" },
+        snapshot.squads.len(),
+        snapshot.gold
+    );
+}
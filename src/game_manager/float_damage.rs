@@ -13,7 +13,8 @@ pub(crate) fn plugin(app: &mut App) {
     app.load_resource::<FontAssets>();
     app.add_systems(
         Update,
-        (spawn_float_damage, despawn_float_damage).run_if(in_state(Screen::Gameplay)),
+        (spawn_float_damage, spawn_float_heal, despawn_float_damage)
+            .run_if(in_state(Screen::Gameplay)),
     );
 }
 #[allow(dead_code)]
@@ -44,37 +45,82 @@ fn spawn_float_damage(
             continue;
         };
 
-        let pos = global_transform.translation();
-        let start = Vec3::new(pos.x, pos.y + 32.0, 10.0);
-        let end = Vec3::new(pos.x, pos.y + 92.0, 10.0);
-
-        let tween = Tween::new(
-            EaseFunction::QuadraticOut,
-            std::time::Duration::from_secs_f32(0.5),
-            TransformPositionLens { start, end },
-        );
-
         let color = if enemy_unit.is_some() {
             Color::WHITE
         } else {
             palette.brown_medium_red
         };
 
-        commands.spawn((
-            FloatDamageText,
-            ImageFontSpriteText::default()
-                .color(color)
-                .letter_spacing(LetterSpacing::Pixel(2)),
-            ImageFontText::default()
-                .text(format!("{}", ev.damage as i32))
-                .font(assets.load("image_font/example_variable_width_font.image_font.ron"))
-                .font_height(if ev.damage >= 10.0 { 48.0 } else { 24.0 }),
-            Transform::from_translation(start),
-            TweenAnim::new(tween),
-        ));
+        spawn_float_text(
+            &mut commands,
+            &assets,
+            global_transform.translation(),
+            format!("{}", ev.damage as i32),
+            color,
+            ev.damage >= 10.0,
+        );
     }
 }
 
+fn spawn_float_heal(
+    mut ev_heal: MessageReader<HealMessage>,
+    q_transform: Query<&GlobalTransform>,
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    palette: Res<ColorPalette>,
+) {
+    for ev in ev_heal.read() {
+        let Ok(global_transform) = q_transform.get(ev.target) else {
+            continue;
+        };
+        // Heal over time ticks for fractions of a point; only show whole numbers
+        if ev.amount < 1.0 {
+            continue;
+        }
+
+        spawn_float_text(
+            &mut commands,
+            &assets,
+            global_transform.translation(),
+            format!("+{}", ev.amount as i32),
+            palette.green_light,
+            false,
+        );
+    }
+}
+
+/// A number that rises from above `pos` and fades out.
+fn spawn_float_text(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    pos: Vec3,
+    text: String,
+    color: Color,
+    large: bool,
+) {
+    let start = Vec3::new(pos.x, pos.y + 32.0, 10.0);
+    let end = Vec3::new(pos.x, pos.y + 92.0, 10.0);
+
+    let tween = Tween::new(
+        EaseFunction::QuadraticOut,
+        std::time::Duration::from_secs_f32(0.5),
+        TransformPositionLens { start, end },
+    );
+
+    commands.spawn((
+        FloatDamageText,
+        ImageFontSpriteText::default()
+            .color(color)
+            .letter_spacing(LetterSpacing::Pixel(2)),
+        ImageFontText::default()
+            .text(text)
+            .font(assets.load("image_font/example_variable_width_font.image_font.ron"))
+            .font_height(if large { 48.0 } else { 24.0 }),
+        Transform::from_translation(start),
+        TweenAnim::new(tween),
+    ));
+}
+
 fn despawn_float_damage(
     mut anim_completed: MessageReader<AnimCompletedEvent>,
     q_float: Query<Entity, With<FloatDamageText>>,
@@ -1,7 +1,17 @@
 mod space;
 
 mod dark;
+
+mod terrain;
+pub(crate) use terrain::*;
+
 pub(crate) fn plugin(app: &mut bevy::app::App) {
     space::plugin(app);
     dark::plugin(app);
+    terrain::plugin(app);
+}
+
+/// Terrain effects on units, for the headless simulation.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    terrain::headless_plugin(app);
 }
@@ -0,0 +1,241 @@
+use bevy::platform::collections::HashMap;
+use bevy_ecs_ldtk::{IntGridCell, LdtkIntCell, LevelEvent, app::LdtkIntCellAppExt};
+
+use crate::prelude::*;
+
+/// IntGrid values of terrain cells on the "Wall" layer.
+const MUD_CELL: i32 = 4;
+const HIGH_GROUND_CELL: i32 = 5;
+const FOG_CELL: i32 = 6;
+
+/// Side of a terrain cell, matching the LDtk grid.
+const TERRAIN_CELL_SIZE: f32 = 64.0;
+
+/// Speed lost wading through mud.
+const MUD_SPEED: f32 = -0.4;
+/// Range gained on high ground.
+const HIGH_GROUND_RANGE: f32 = 0.2;
+/// Defense gained on high ground.
+const HIGH_GROUND_DEFENSE: f32 = 2.0;
+/// Range ranged units lose inside dream fog.
+const FOG_RANGED_RANGE: f32 = -0.5;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.register_ldtk_int_cell::<TerrainBundle>(MUD_CELL);
+    app.register_ldtk_int_cell::<TerrainBundle>(HIGH_GROUND_CELL);
+    app.register_ldtk_int_cell::<TerrainBundle>(FOG_CELL);
+
+    headless_plugin(app);
+    app.add_observer(add_terrain_sprite);
+    app.add_systems(Update, rebuild_terrain_map);
+}
+
+/// Terrain modifiers without the LDtk level; the [`TerrainMap`] is filled in by whoever
+/// lays out the ground.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    app.init_resource::<TerrainMap>();
+    app.add_systems(
+        Update,
+        apply_terrain_modifiers
+            .run_if(in_state(GameState::Battle).or(in_state(GameState::Preparing))),
+    );
+}
+
+/// Ground that changes the stats of the units standing on it.
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Reflect,
+)]
+#[require(Name::new("Terrain"))]
+pub enum Terrain {
+    /// Slows everyone down.
+    #[default]
+    Mud,
+    /// Longer reach and better footing.
+    HighGround,
+    /// Dream fog: archers can't see far.
+    Fog,
+}
+
+impl From<IntGridCell> for Terrain {
+    fn from(cell: IntGridCell) -> Self {
+        match cell.value {
+            HIGH_GROUND_CELL => Terrain::HighGround,
+            FOG_CELL => Terrain::Fog,
+            _ => Terrain::Mud,
+        }
+    }
+}
+
+impl Terrain {
+    /// What this ground does to a unit standing on it.
+    pub fn modifiers(self, ranged: bool) -> Vec<StatModifier> {
+        let source = ModifierSource::Terrain(self);
+        match self {
+            Terrain::Mud => vec![StatModifier::percent(Stat::Speed, source, MUD_SPEED)],
+            Terrain::HighGround => vec![
+                StatModifier::percent(Stat::AttackRange, source.clone(), HIGH_GROUND_RANGE),
+                StatModifier::flat(Stat::Defense, source, HIGH_GROUND_DEFENSE),
+            ],
+            Terrain::Fog if ranged => vec![StatModifier::percent(
+                Stat::AttackRange,
+                source,
+                FOG_RANGED_RANGE,
+            )],
+            Terrain::Fog => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Bundle, LdtkIntCell)]
+pub struct TerrainBundle {
+    #[from_int_grid_cell]
+    terrain: Terrain,
+}
+
+/// Terrain of every cell of the current level that has any.
+#[derive(Resource, Default)]
+pub struct TerrainMap {
+    /// World position of a cell corner, so cells line up with the level's grid.
+    origin: Vec2,
+    cells: HashMap<IVec2, Terrain>,
+}
+
+impl TerrainMap {
+    /// Refills the map from the centers of terrain cells, on a grid with a corner at `origin`.
+    pub fn rebuild(&mut self, origin: Vec2, cells: impl IntoIterator<Item = (Vec2, Terrain)>) {
+        self.origin = origin;
+        self.cells.clear();
+        for (pos, terrain) in cells {
+            let cell = self.cell_of(pos);
+            self.cells.insert(cell, terrain);
+        }
+    }
+
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    /// Centers of the terrain cells, as [`TerrainMap::rebuild`] takes them.
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, Terrain)> + '_ {
+        self.cells.iter().map(|(cell, terrain)| {
+            let center = self.origin + (cell.as_vec2() + 0.5) * TERRAIN_CELL_SIZE;
+            (center, *terrain)
+        })
+    }
+
+    fn cell_of(&self, pos: Vec2) -> IVec2 {
+        ((pos - self.origin) / TERRAIN_CELL_SIZE).floor().as_ivec2()
+    }
+
+    pub fn terrain_at(&self, pos: Vec2) -> Option<Terrain> {
+        self.cells.get(&self.cell_of(pos)).copied()
+    }
+}
+
+/// The ground a unit was last seen on, so modifiers only change when it moves onto new ground.
+#[derive(Component, Default, PartialEq)]
+struct StandingOn(Option<Terrain>);
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// IntGrid layers aren't rendered, so terrain draws itself.
+fn add_terrain_sprite(
+    add: On<Add, Terrain>,
+    q_terrain: Query<&Terrain>,
+    palette: Res<ColorPalette>,
+    mut commands: Commands,
+) {
+    let Ok(terrain) = q_terrain.get(add.entity) else {
+        return;
+    };
+    let (color, layer) = match terrain {
+        Terrain::Mud => (palette.brown_medium.with_alpha(0.6), SpriteLayer::Grid),
+        Terrain::HighGround => (palette.green_medium.with_alpha(0.6), SpriteLayer::Grid),
+        Terrain::Fog => (palette.purple_light.with_alpha(0.35), SpriteLayer::Dark),
+    };
+    commands.entity(add.entity).insert((
+        Sprite {
+            color,
+            custom_size: Some(Vec2::splat(TERRAIN_CELL_SIZE)),
+            ..default()
+        },
+        layer,
+    ));
+}
+
+fn rebuild_terrain_map(
+    mut level_messages: MessageReader<LevelEvent>,
+    q_terrain: Query<(&Terrain, &GlobalTransform)>,
+    mut terrain_map: ResMut<TerrainMap>,
+) {
+    for message in level_messages.read() {
+        // Use Transformed (not Spawned) so GlobalTransforms are guaranteed up-to-date
+        if !matches!(message, LevelEvent::Transformed(_)) {
+            continue;
+        }
+
+        let half_cell = Vec2::splat(TERRAIN_CELL_SIZE / 2.0);
+        let origin = q_terrain
+            .iter()
+            .next()
+            .map_or(terrain_map.origin, |(_, first)| {
+                (first.translation().truncate() - half_cell)
+                    .rem_euclid(Vec2::splat(TERRAIN_CELL_SIZE))
+            });
+        terrain_map.rebuild(
+            origin,
+            q_terrain
+                .iter()
+                .map(|(terrain, transform)| (transform.translation().truncate(), *terrain)),
+        );
+        debug!("TerrainMap rebuilt: {} cells", terrain_map.cells.len());
+    }
+}
+
+/// Swaps a unit's terrain modifiers whenever it steps onto different ground.
+fn apply_terrain_modifiers(
+    terrain_map: Res<TerrainMap>,
+    mut commands: Commands,
+    mut q_units: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &mut StatModifiers,
+            Option<&mut StandingOn>,
+            Has<Ranged>,
+        ),
+        With<Unit>,
+    >,
+) {
+    for (entity, transform, mut modifiers, standing_on, ranged) in &mut q_units {
+        let terrain = terrain_map.terrain_at(transform.translation().truncate());
+        let previous = match standing_on {
+            Some(mut standing_on) if standing_on.0 != terrain => {
+                std::mem::replace(&mut standing_on.0, terrain)
+            }
+            Some(_) => continue,
+            None => {
+                commands.entity(entity).insert(StandingOn(terrain));
+                None
+            }
+        };
+
+        if let Some(previous) = previous {
+            modifiers.replace_source(ModifierSource::Terrain(previous), []);
+        }
+        if let Some(terrain) = terrain {
+            modifiers.replace_source(ModifierSource::Terrain(terrain), terrain.modifiers(ranged));
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use super::super::{EnemyMemory, Memory, PlayerMemory};
 use crate::prelude::*;
-use bevy_rand::{global::GlobalRng, prelude::ChaCha8Rng};
 use rock_materials::ChromaticAberrationV2Material;
 use rand::Rng;
 
@@ -34,19 +33,33 @@ pub struct BigEyeActive {
     pub timer: Timer,
 }
 
-/// Placed on the squad entity. 1 attack = 1% outgoing damage bonus, capped at 100%.
-#[derive(Component, Default, Reflect)]
-pub struct BigEyeDamageBonus(pub f32);
+/// Seconds the damage bonus lasts once observation is done.
+const BIG_EYE_ACTIVE_SECS: f32 = 3.0;
+
+/// Countdown to the next BigEye spawn. Reset every battle so each fight starts from the same state.
+#[derive(Resource, Default)]
+struct BigEyeSpawnTimer(Option<Timer>);
 
 pub(super) fn plugin(app: &mut bevy::app::App) {
+    headless_plugin(app);
+    app.add_systems(
+        Update,
+        (setup_big_eye_mesh, update_big_eye_fill).run_if(in_state(GameState::Battle)),
+    );
+}
+
+/// BigEye spawning and damage bonus logic, without the mesh and fill visuals.
+pub(super) fn headless_plugin(app: &mut bevy::app::App) {
+    app.init_resource::<BigEyeSpawnTimer>();
+    app.add_systems(OnEnter(GameState::Battle), |mut commands: Commands| {
+        commands.insert_resource(BigEyeSpawnTimer::default());
+    });
     app.add_systems(
         Update,
         (
             spawn_big_eye_on_buff_timer,
             big_eye_observe_system,
             big_eye_active_system,
-            setup_big_eye_mesh,
-            update_big_eye_fill,
         )
             .run_if(in_state(GameState::Battle)),
     );
@@ -56,12 +69,12 @@ pub(super) fn plugin(app: &mut bevy::app::App) {
 fn spawn_big_eye_on_buff_timer(
     mut commands: Commands,
     time: Res<Time>,
-    mut timer: Local<Option<Timer>>,
+    mut timer: ResMut<BigEyeSpawnTimer>,
     q_buffed_squads: Query<(Entity, &RootStationSquad, &Faction, &SquadHitCount), With<BigEyeBuff>>,
     q_unit_transform: Query<&GlobalTransform>,
-    mut rng: Single<&mut ChaCha8Rng, With<GlobalRng>>,
+    mut rng: ResMut<BattleRng>,
 ) {
-    let timer = timer.get_or_insert_with(|| {
+    let timer = timer.0.get_or_insert_with(|| {
         let duration = rng.random_range(10.0..=15.0);
         Timer::from_seconds(duration, TimerMode::Once)
     });
@@ -188,6 +201,8 @@ fn big_eye_observe_system(
     time: Res<Time>,
     mut q_observing: Query<(Entity, &mut BigEyeObserving)>,
     q_hit_count: Query<&SquadHitCount>,
+    q_squads: Query<&RootStationSquad>,
+    mut q_modifiers: Query<&mut StatModifiers>,
 ) {
     for (entity, mut obs) in &mut q_observing {
         obs.timer.tick(time.delta());
@@ -219,13 +234,26 @@ fn big_eye_observe_system(
             bonus * 100.0
         );
 
-        commands.entity(obs.squad).insert(BigEyeDamageBonus(bonus));
+        // 1 attack = 1% outgoing damage bonus for every squad member, capped at 100%
+        let members = q_squads
+            .get(obs.squad)
+            .into_iter()
+            .flat_map(|squad| squad.iter());
+        for member in members {
+            if let Ok(mut modifiers) = q_modifiers.get_mut(member) {
+                modifiers.add(
+                    StatModifier::percent(Stat::Damage, ModifierSource::BigEye, bonus)
+                        .lasting(BIG_EYE_ACTIVE_SECS)
+                        .stacking(Stacking::Strongest),
+                );
+            }
+        }
         commands
             .entity(entity)
             .remove::<BigEyeObserving>()
             .insert(BigEyeActive {
                 squad: obs.squad,
-                timer: Timer::from_seconds(3.0, TimerMode::Once),
+                timer: Timer::from_seconds(BIG_EYE_ACTIVE_SECS, TimerMode::Once),
             });
     }
 }
@@ -265,7 +293,7 @@ fn setup_big_eye_mesh(
     }
 }
 
-/// Ticks active timer. On finish, removes damage bonus and despawns BigEye.
+/// Ticks active timer. On finish, despawns BigEye; the damage bonus wears off on its own.
 fn big_eye_active_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -276,9 +304,8 @@ fn big_eye_active_system(
             continue;
         }
 
-        commands.entity(active.squad).remove::<BigEyeDamageBonus>();
         info!(
-            "[BigEye] Active phase ended, damage bonus removed from squad {:?}",
+            "[BigEye] Active phase ended, damage bonus wears off for squad {:?}",
             active.squad
         );
 
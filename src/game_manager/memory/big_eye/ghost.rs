@@ -44,7 +44,7 @@ fn handle_big_eye_button_press(
     for (button_entity, interaction) in q_button.iter() {
         if *interaction == Interaction::Pressed && q_ghost.is_empty() {
             // Get the BigEye cost from stats cache
-            let Some(memory_row) = memory_stats.stats.get("BigEye") else {
+            let Some(memory_row) = memory_stats.stats.get(MemoryKind::BigEye.id()) else {
                 warn!("[BigEye] No stats found for BigEye");
                 return;
             };
@@ -187,6 +187,7 @@ fn despawn_big_eye_ghost_on_release(
     q_squads: Query<&super::big_eye::BigEyeBuff>,
     memory_stats: Res<MemoryStatsCache>,
     mut player_gold: ResMut<PlayerGold>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
 ) {
     if !mouse.just_released(MouseButton::Left) || q_ghost.is_empty() {
         return;
@@ -232,6 +233,7 @@ fn despawn_big_eye_ghost_on_release(
                 .entity(belong_to_squad.0)
                 .insert(super::big_eye::BigEyeBuff);
             buff_applied = true;
+            placement_msg.write(PlacementChangedMessage);
 
             commands.entity(ghost_entity).insert(ShrinkDespawn);
 
@@ -244,7 +246,7 @@ fn despawn_big_eye_ghost_on_release(
         info!("[BigEye] No unit found — refunding cost and playing invalid SFX");
 
         // Refund the cost
-        if let Some(memory_row) = memory_stats.stats.get("BigEye") {
+        if let Some(memory_row) = memory_stats.stats.get(MemoryKind::BigEye.id()) {
             player_gold.amount = (player_gold.amount as i32 + memory_row.price) as u32;
             info!(
                 "[BigEye] Refunded {}. Current gold: {}",
@@ -1,4 +1,3 @@
-use bevy_rand::{global::GlobalRng, prelude::ChaCha8Rng};
 use rand::Rng;
 use rock_materials::LaserBeamMaterial;
 
@@ -15,6 +14,17 @@ const BEAM_DAMAGE_INTERVAL: f32 = 0.5;
 const BEAM_OVERSHOOT: f32 = 800.0;
 
 pub(crate) fn plugin(app: &mut App) {
+    headless_plugin(app);
+    app.add_systems(
+        Update,
+        add_laser_mesh
+            .after(spawn_laser_on_big_eye_appear)
+            .run_if(in_state(GameState::Battle)),
+    );
+}
+
+/// Laser aiming and damage, without the beam mesh.
+pub(crate) fn headless_plugin(app: &mut App) {
     app.add_systems(
         Update,
         (spawn_laser_on_big_eye_appear, tick_laser_and_fire).run_if(in_state(GameState::Battle)),
@@ -36,9 +46,7 @@ fn spawn_laser_on_big_eye_appear(
     new_big_eyes: Query<(&Transform, Has<PlayerMemory>, Has<EnemyMemory>), Added<BigEyeActive>>,
     player_units: Query<(Entity, &GlobalTransform), With<PlayerFaction>>,
     enemy_units: Query<(Entity, &GlobalTransform), With<EnemyFaction>>,
-    mut rng: Single<&mut ChaCha8Rng, With<GlobalRng>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut laser_materials: ResMut<Assets<LaserBeamMaterial>>,
+    mut rng: ResMut<BattleRng>,
 ) {
     for (big_eye_transform, is_player_memory, is_enemy_memory) in &new_big_eyes {
         let origin = big_eye_transform.translation.truncate();
@@ -78,12 +86,6 @@ fn spawn_laser_on_big_eye_appear(
         let midpoint = origin + direction * (beam_length * 0.5);
         let angle = direction.y.atan2(direction.x);
 
-        let material = laser_materials.add(LaserBeamMaterial {
-            resolution: Vec2::new(beam_length, BEAM_HEIGHT),
-            alpha: 1.0,
-            _padding: 0.0,
-        });
-
         commands.trigger(CameraShakeEvent);
         commands.trigger(SFXEvent::space("beam", origin));
         commands.spawn((
@@ -99,8 +101,6 @@ fn spawn_laser_on_big_eye_appear(
                     t
                 },
             },
-            Mesh2d(meshes.add(Rectangle::new(beam_length, BEAM_HEIGHT))),
-            MeshMaterial2d(material),
             Transform::from_xyz(midpoint.x, midpoint.y, 0.0)
                 .with_rotation(Quat::from_rotation_z(angle)),
             SpriteLayer::VFX,
@@ -109,6 +109,26 @@ fn spawn_laser_on_big_eye_appear(
     }
 }
 
+/// Gives newly fired lasers their beam mesh.
+fn add_laser_mesh(
+    mut commands: Commands,
+    q_new_lasers: Query<(Entity, &BigEyeLaser), Added<BigEyeLaser>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut laser_materials: ResMut<Assets<LaserBeamMaterial>>,
+) {
+    for (entity, laser) in &q_new_lasers {
+        let material = laser_materials.add(LaserBeamMaterial {
+            resolution: Vec2::new(laser.beam_length, BEAM_HEIGHT),
+            alpha: 1.0,
+            _padding: 0.0,
+        });
+        commands.entity(entity).insert((
+            Mesh2d(meshes.add(Rectangle::new(laser.beam_length, BEAM_HEIGHT))),
+            MeshMaterial2d(material),
+        ));
+    }
+}
+
 fn tick_laser_and_fire(
     mut commands: Commands,
     time: Res<Time>,
@@ -147,6 +167,7 @@ fn tick_laser_and_fire(
                         laser_entity,
                         target_entity,
                         BEAM_DAMAGE_PER_TICK,
+                        DamageType::Dream,
                     ));
                 }
             }
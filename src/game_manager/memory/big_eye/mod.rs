@@ -10,3 +10,9 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
     ghost::plugin(app);
     big_eye::plugin(app);
 }
+
+/// Battle-side BigEye logic, without ghosts, meshes or materials.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    laser::headless_plugin(app);
+    big_eye::headless_plugin(app);
+}
@@ -44,7 +44,7 @@ fn handle_golden_heart_button_press(
     for (button_entity, interaction) in q_button.iter() {
         if *interaction == Interaction::Pressed && q_ghost.is_empty() {
             // Get the GoldenHeart cost from stats cache
-            let Some(memory_row) = memory_stats.stats.get("GoldenHeart") else {
+            let Some(memory_row) = memory_stats.stats.get(MemoryKind::GoldenHeart.id()) else {
                 warn!("[GoldenHeart] No stats found for GoldenHeart");
                 return;
             };
@@ -187,6 +187,7 @@ fn despawn_golden_heart_ghost_on_release(
     q_squads: Query<&super::golden_heart::GoldenHeartBuff>,
     memory_stats: Res<MemoryStatsCache>,
     mut player_gold: ResMut<PlayerGold>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
 ) {
     if !mouse.just_released(MouseButton::Left) || q_ghost.is_empty() {
         return;
@@ -232,6 +233,7 @@ fn despawn_golden_heart_ghost_on_release(
                 .entity(belong_to_squad.0)
                 .insert(super::golden_heart::GoldenHeartBuff);
             buff_applied = true;
+            placement_msg.write(PlacementChangedMessage);
 
             commands.entity(ghost_entity).insert(ShrinkDespawn);
 
@@ -244,7 +246,7 @@ fn despawn_golden_heart_ghost_on_release(
         info!("[GoldenHeart] No unit found — refunding cost and playing invalid SFX");
 
         // Refund the cost
-        if let Some(memory_row) = memory_stats.stats.get("GoldenHeart") {
+        if let Some(memory_row) = memory_stats.stats.get(MemoryKind::GoldenHeart.id()) {
             player_gold.amount = (player_gold.amount as i32 + memory_row.price) as u32;
             info!(
                 "[GoldenHeart] Refunded {}. Current gold: {}",
@@ -1,7 +1,6 @@
 use crate::prelude::*;
 
 use crate::game_manager::memory::{EnemyMemory, PlayerMemory};
-use bevy_rand::{global::GlobalRng, prelude::ChaCha8Rng};
 use rock_materials::ChromaticAberrationV2Material;
 use rand::Rng;
 
@@ -34,15 +33,30 @@ pub struct GoldenHeartActive {
     pub timer: Timer,
 }
 
+/// Countdown to the next GoldenHeart spawn. Reset every battle so each fight starts from the same state.
+#[derive(Resource, Default)]
+struct GoldenHeartSpawnTimer(Option<Timer>);
+
 pub(crate) fn plugin(app: &mut App) {
+    headless_plugin(app);
+    app.add_systems(
+        Update,
+        (setup_golden_heart_mesh, update_golden_heart_fill).run_if(in_state(GameState::Battle)),
+    );
+}
+
+/// GoldenHeart spawning and observation logic, without the mesh and fill visuals.
+pub(crate) fn headless_plugin(app: &mut App) {
+    app.init_resource::<GoldenHeartSpawnTimer>();
+    app.add_systems(OnEnter(GameState::Battle), |mut commands: Commands| {
+        commands.insert_resource(GoldenHeartSpawnTimer::default());
+    });
     app.add_systems(
         Update,
         (
             spawn_golden_heart_on_buff_timer,
             golden_heart_observe_system,
             golden_heart_active_system,
-            setup_golden_heart_mesh,
-            update_golden_heart_fill,
         )
             .run_if(in_state(GameState::Battle)),
     );
@@ -52,15 +66,15 @@ pub(crate) fn plugin(app: &mut App) {
 fn spawn_golden_heart_on_buff_timer(
     mut commands: Commands,
     time: Res<Time>,
-    mut timer: Local<Option<Timer>>,
+    mut timer: ResMut<GoldenHeartSpawnTimer>,
     q_buffed_squads: Query<
         (Entity, &RootStationSquad, &Faction, &SquadTakeHitCount),
         With<GoldenHeartBuff>,
     >,
     q_unit_transform: Query<&GlobalTransform>,
-    mut rng: Single<&mut ChaCha8Rng, With<GlobalRng>>,
+    mut rng: ResMut<BattleRng>,
 ) {
-    let timer = timer.get_or_insert_with(|| {
+    let timer = timer.0.get_or_insert_with(|| {
         let duration = rng.random_range(10.0..=15.0);
         Timer::from_seconds(duration, TimerMode::Once)
     });
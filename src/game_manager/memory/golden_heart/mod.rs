@@ -1,5 +1,6 @@
 mod golden_heart;
 pub use golden_heart::GoldenHeart;
+pub(crate) use golden_heart::GoldenHeartBuff;
 
 mod wave;
 
@@ -10,3 +11,9 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
     wave::plugin(app);
     ghost::plugin(app);
 }
+
+/// Battle-side GoldenHeart logic, without ghosts, meshes or materials.
+pub(crate) fn headless_plugin(app: &mut bevy::app::App) {
+    golden_heart::headless_plugin(app);
+    wave::headless_plugin(app);
+}
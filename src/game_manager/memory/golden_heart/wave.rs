@@ -9,6 +9,9 @@ const MESH_SIZE: f32 = 2000.0;
 const WAVE_SPEED: f32 = 0.15;
 const WAVE_THICKNESS_UV: f32 = 0.1;
 const WAVE_DAMAGE: f32 = 10.0;
+/// Outward shove of the wave before the unit's weight, in pixels.
+const WAVE_KNOCKBACK: f32 = 90.0;
+const WAVE_STAGGER: f32 = 0.6;
 
 #[derive(Component)]
 struct PendingWaveDistortion {
@@ -28,6 +31,12 @@ struct WaveDistortionVfx {
 }
 
 pub(crate) fn plugin(app: &mut App) {
+    headless_plugin(app);
+    app.add_systems(Update, add_wave_mesh.after(tick_pending_wave));
+}
+
+/// Wave timing and damage, without the distortion mesh.
+pub(crate) fn headless_plugin(app: &mut App) {
     app.add_systems(
         Update,
         (
@@ -62,9 +71,6 @@ fn detect_golden_heart(
 fn tick_pending_wave(
     mut commands: Commands,
     time: Res<Time>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<WaveDistortionMaterial>>,
-    mut images: ResMut<Assets<Image>>,
     mut q_pending: Query<(Entity, &mut PendingWaveDistortion)>,
 ) {
     for (entity, mut pending) in &mut q_pending {
@@ -73,6 +79,32 @@ fn tick_pending_wave(
             continue;
         }
 
+        commands.spawn((
+            Name::new("GoldenHeart WaveDistortion VFX"),
+            WaveDistortionVfx {
+                lifetime: Timer::from_seconds(5.0, TimerMode::Once),
+                start_time: time.elapsed_secs(),
+                wave_position: pending.position.xy(),
+                targets_enemies: pending.targets_enemies,
+                already_hit: HashSet::new(),
+            },
+            Transform::from_translation(pending.position),
+            SpriteLayer::VFX,
+        ));
+
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Gives newly spawned waves their distortion mesh.
+fn add_wave_mesh(
+    mut commands: Commands,
+    q_new_waves: Query<(Entity, &WaveDistortionVfx), Added<WaveDistortionVfx>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<WaveDistortionMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (entity, vfx) in &q_new_waves {
         let translucent = images.add(Image::new_fill(
             bevy::render::render_resource::Extent3d {
                 width: 1,
@@ -85,31 +117,18 @@ fn tick_pending_wave(
             bevy::asset::RenderAssetUsages::RENDER_WORLD,
         ));
 
-        let start_time = time.elapsed_secs();
         let material = materials.add(WaveDistortionMaterial {
             texture: translucent,
             wave_center: Vec2::new(0.5, 0.5),
             wave_params: Vec3::new(10.0, 0.8, WAVE_THICKNESS_UV),
             alpha: 1.0,
-            start_time,
+            start_time: vfx.start_time,
         });
 
-        commands.spawn((
-            Name::new("GoldenHeart WaveDistortion VFX"),
-            WaveDistortionVfx {
-                lifetime: Timer::from_seconds(5.0, TimerMode::Once),
-                start_time,
-                wave_position: pending.position.xy(),
-                targets_enemies: pending.targets_enemies,
-                already_hit: HashSet::new(),
-            },
+        commands.entity(entity).insert((
             Mesh2d(meshes.add(Rectangle::new(MESH_SIZE, MESH_SIZE))),
             MeshMaterial2d(material),
-            Transform::from_translation(pending.position),
-            SpriteLayer::VFX,
         ));
-
-        commands.entity(entity).despawn();
     }
 }
 
@@ -152,11 +171,20 @@ fn wave_damage(
                 continue;
             }
 
-            let dist = vfx
-                .wave_position
-                .distance(unit_gtransform.translation().xy());
+            let offset = unit_gtransform.translation().xy() - vfx.wave_position;
+            let dist = offset.length();
             if dist >= ring_radius - half_band && dist <= ring_radius + half_band {
-                commands.trigger(AttackEvent::new(wave_entity, unit_entity, WAVE_DAMAGE));
+                commands.trigger(AttackEvent::new(
+                    wave_entity,
+                    unit_entity,
+                    WAVE_DAMAGE,
+                    DamageType::Dream,
+                ));
+                commands.trigger(KnockbackEvent {
+                    target: unit_entity,
+                    impulse: offset.normalize_or_zero() * WAVE_KNOCKBACK,
+                    stagger: WAVE_STAGGER,
+                });
                 vfx.already_hit.insert(unit_entity);
             }
         }
@@ -8,6 +8,7 @@ pub(crate) use big_eye::*;
 // pub(crate) use big_hand::*;
 mod golden_heart;
 pub use golden_heart::GoldenHeart;
+pub(crate) use golden_heart::GoldenHeartBuff;
 mod squad_hit_count;
 pub(crate) use squad_hit_count::*;
 mod squad_take_hit_count;
@@ -23,6 +24,53 @@ pub(crate) fn plugin(app: &mut App) {
     app.add_systems(Update, despawn_expired_memory);
 }
 
+/// Memory effects that act during battle, without ghosts, meshes or materials.
+pub(crate) fn headless_plugin(app: &mut App) {
+    big_eye::headless_plugin(app);
+    golden_heart::headless_plugin(app);
+    squad_hit_count::plugin(app);
+    squad_take_hit_count::plugin(app);
+    app.add_systems(Update, despawn_expired_memory);
+}
+
+/// Memory buffs a squad can carry into battle.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    BigEye,
+    GoldenHeart,
+}
+
+impl MemoryKind {
+    pub const ALL: [Self; 2] = [MemoryKind::BigEye, MemoryKind::GoldenHeart];
+
+    /// The `id` of this memory's row in `all.memory.csv`.
+    pub fn id(self) -> &'static str {
+        match self {
+            MemoryKind::BigEye => "BigEye",
+            MemoryKind::GoldenHeart => "GoldenHeart",
+        }
+    }
+
+    /// The memories carried by a squad with the given buffs.
+    pub fn from_buffs(big_eye: bool, golden_heart: bool) -> Vec<Self> {
+        [
+            big_eye.then_some(MemoryKind::BigEye),
+            golden_heart.then_some(MemoryKind::GoldenHeart),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Inserts this memory's buff on a squad entity.
+    pub fn insert_buff(self, entity_commands: &mut EntityCommands) {
+        match self {
+            MemoryKind::BigEye => entity_commands.insert(BigEyeBuff),
+            MemoryKind::GoldenHeart => entity_commands.insert(GoldenHeartBuff),
+        };
+    }
+}
+
 #[derive(Component, Default)]
 pub struct MemoryBuff;
 
@@ -21,6 +21,7 @@ pub(crate) use vfx::*;
 pub(crate) use camera::*;
 
 mod land;
+pub(crate) use land::*;
 
 mod army;
 pub(crate) use army::*;
@@ -55,6 +56,8 @@ mod background;
 
 mod float_damage;
 
+pub(crate) mod simulation;
+
 /// System sets for ordering battle systems.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BattleSystems {
@@ -65,17 +68,11 @@ pub enum BattleSystems {
 }
 
 pub fn game_manager_plugin(app: &mut App) {
-    app.add_sub_state::<GameState>();
     // app.configure_sets(
     //     OnEnter(GameState::Preparing),
     //     DaySetupSystems::InitResources.before(DaySetupSystems::SpawnEntities),
     // );
-    app.configure_sets(
-        Update,
-        BattleSystems::UpdateUnitValue
-            .before(BattleSystems::CalculateCombatFlux)
-            .run_if(in_state(GameState::Battle).or(in_state(GameState::Preparing))),
-    );
+    configure_game_state(app);
     // app.add_systems(Update, leave_loading_state);
     // Order new `AppSet` variants by adding them here:
     app.configure_sets(
@@ -116,6 +113,18 @@ pub fn game_manager_plugin(app: &mut App) {
     float_damage::plugin(app);
     app.add_systems(Startup, auto_start_new_game);
 }
+/// Registers [`GameState`] and orders [`BattleSystems`] within it.
+/// Shared with the headless battle simulation.
+pub(crate) fn configure_game_state(app: &mut App) {
+    app.add_sub_state::<GameState>();
+    app.configure_sets(
+        Update,
+        BattleSystems::UpdateUnitValue
+            .before(BattleSystems::CalculateCombatFlux)
+            .run_if(in_state(GameState::Battle).or(in_state(GameState::Preparing))),
+    );
+}
+
 #[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub enum PostPhysicsAppSystems {
     /// Tick timers.
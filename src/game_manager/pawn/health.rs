@@ -6,7 +6,7 @@ pub struct Health {
     max: f32,
 }
 impl Health {
-    pub fn _new(current: f32, max: f32) -> Self {
+    pub fn new(current: f32, max: f32) -> Self {
         Self { current, max }
     }
     pub fn is_alive(&self) -> bool {
@@ -15,8 +15,15 @@ impl Health {
     pub fn take_damage(&mut self, amount: f32) {
         self.current = self.current - amount;
     }
-    pub fn _heal(&mut self, amount: f32) {
-        self.current = self.current + amount;
+    /// Restores up to `amount` health, never past the maximum. The dead stay dead.
+    pub fn heal(&mut self, amount: f32) {
+        if !self.is_alive() {
+            return;
+        }
+        self.current = (self.current + amount).min(self.max);
+    }
+    pub fn is_injured(&self) -> bool {
+        self.current < self.max
     }
     pub fn new_full(max: f32) -> Self {
         Self { current: max, max }
@@ -9,8 +9,9 @@ use crate::prelude::*;
     UnitCollider,
     Target,
     AttackTimer::new(1.),
-    ActiveBuffs::default(),
-    ActiveDeBuffs::default()
+    ActiveEffects::default(),
+    Knockback,
+    Stagger
 )]
 pub struct Pawn;
 
@@ -1,6 +1,7 @@
 mod boundary;
 pub(crate) use boundary::*;
 
+mod obstacle;
 mod spawn;
 mod switch_level;
 mod tree;
@@ -17,6 +18,7 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
     tree::plugin(app);
     switch_level::plugin(app);
     boundary::plugin(app);
+    obstacle::plugin(app);
 }
 
 /// A [`Resource`] that contains all the assets needed to spawn the level.
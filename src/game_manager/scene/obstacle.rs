@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+use bevy_ecs_ldtk::{
+    IntGridCell, LdtkIntCell, LdtkProjectHandle, LevelEvent, LevelIid, app::LdtkIntCellAppExt,
+    assets::LdtkProject, prelude::RawLevelAccessor,
+};
+
+/// IntGrid value of a wall cell on the "Wall" layer.
+const WALL_CELL: i32 = 2;
+/// IntGrid value of a river cell on the "Wall" layer.
+const RIVER_CELL: i32 = 3;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.register_ldtk_int_cell::<ObstacleBundle>(WALL_CELL);
+    app.register_ldtk_int_cell::<ObstacleBundle>(RIVER_CELL);
+
+    app.add_observer(add_obstacle_sprite);
+    app.add_systems(Update, rebuild_nav_grid);
+}
+
+/// A level cell units can't cross. They path around it instead.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[require(Name::new("Obstacle"))]
+pub enum Obstacle {
+    #[default]
+    Wall,
+    River,
+}
+
+impl From<IntGridCell> for Obstacle {
+    fn from(cell: IntGridCell) -> Self {
+        match cell.value {
+            RIVER_CELL => Obstacle::River,
+            _ => Obstacle::Wall,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Bundle, LdtkIntCell)]
+pub struct ObstacleBundle {
+    #[from_int_grid_cell]
+    obstacle: Obstacle,
+}
+
+/// IntGrid layers aren't rendered, so obstacles draw themselves.
+fn add_obstacle_sprite(
+    add: On<Add, Obstacle>,
+    q_obstacle: Query<&Obstacle>,
+    palette: Res<ColorPalette>,
+    mut commands: Commands,
+) {
+    let Ok(obstacle) = q_obstacle.get(add.entity) else {
+        return;
+    };
+    let color = match obstacle {
+        Obstacle::Wall => palette.brown_dark,
+        Obstacle::River => palette.blue_medium,
+    };
+    commands.entity(add.entity).insert(Sprite {
+        color,
+        custom_size: Some(Vec2::splat(NAV_CELL_SIZE)),
+        ..default()
+    });
+}
+
+/// Rebuilds the [`NavGrid`] from the obstacles of each level once it is in place.
+fn rebuild_nav_grid(
+    mut level_messages: MessageReader<LevelEvent>,
+    ldtk_projects: Query<&LdtkProjectHandle>,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+    q_levels: Query<(&LevelIid, &GlobalTransform)>,
+    q_obstacles: Query<&GlobalTransform, With<Obstacle>>,
+    mut nav: ResMut<NavGrid>,
+) {
+    for message in level_messages.read() {
+        // Use Transformed (not Spawned) so GlobalTransforms are guaranteed up-to-date
+        let LevelEvent::Transformed(level_iid) = message else {
+            continue;
+        };
+        let Some(ldtk_project) = ldtk_projects
+            .single()
+            .ok()
+            .and_then(|handle| ldtk_project_assets.get(handle))
+        else {
+            continue;
+        };
+        let Some(level) = ldtk_project.get_raw_level_by_iid(level_iid.get()) else {
+            continue;
+        };
+        let Some((_, level_transform)) = q_levels.iter().find(|(iid, _)| *iid == level_iid) else {
+            continue;
+        };
+
+        let min = level_transform.translation().truncate();
+        let area = Rect::from_corners(
+            min,
+            min + Vec2::new(level.px_wid as f32, level.px_hei as f32),
+        );
+        let blocked: Vec<Vec2> = q_obstacles
+            .iter()
+            .map(|transform| transform.translation().truncate())
+            .filter(|pos| area.contains(*pos))
+            .collect();
+        debug!(
+            "NavGrid rebuilt for {}: {} obstacle cells",
+            level.identifier,
+            blocked.len()
+        );
+        nav.rebuild(area, blocked);
+    }
+}
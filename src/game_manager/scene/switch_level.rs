@@ -15,7 +15,8 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
 
 fn increment_round_on_key9(
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut campaign: ResMut<GameProgress>,
+    mut progress: ResMut<GameProgress>,
+    campaign: Res<Campaign>,
     mut next_state: ResMut<NextState<GameState>>,
     current_state: Res<State<GameState>>,
 ) {
@@ -25,25 +26,17 @@ fn increment_round_on_key9(
                 "Cheat: simulating battle win (current state: {:?})",
                 current_state.get()
             );
-            campaign.record_battle(true);
+            progress.record_battle(true, &campaign);
             next_state.set(GameState::WinAndNextDay);
             info!("Cheat: set next state to WinAndNextDay");
         }
     }
 }
 
-fn zoom_for_round(round: usize) -> f32 {
-    match round {
-        1 => 2.0,
-        2 => 2.2,
-        3 => 2.5,
-        _ => 2.5,
-    }
-}
-
 fn switch_to_next_level(
     mut level_selection: ResMut<LevelSelection>,
     progression: Res<GameProgress>,
+    campaign: Res<Campaign>,
     mut screen: ResMut<NextState<Screen>>,
     ldtk_projects: Query<&LdtkProjectHandle>,
     ldtk_project_assets: Res<Assets<LdtkProject>>,
@@ -70,21 +63,11 @@ fn switch_to_next_level(
     debug!("Current round: {}", progression.current_round);
     debug!("Current level selection: {:?}", *level_selection);
 
-    if progression.current_round == 1 {
-        debug!("Switching to Level1");
-        *level_selection = LevelSelection::Identifier("Level1".to_string());
-        zoom_writer.write(CameraZoomMessage(zoom_for_round(1)));
-        player_gold.amount = 3 * 20;
-    } else if progression.current_round == 2 {
-        debug!("Switching to Level2");
-        *level_selection = LevelSelection::Identifier("Level2".to_string());
-        zoom_writer.write(CameraZoomMessage(zoom_for_round(2)));
-        player_gold.amount = 7 * 20;
-    } else if progression.current_round == 3 {
-        debug!("Switching to Level3");
-        *level_selection = LevelSelection::Identifier("Level3".to_string());
-        zoom_writer.write(CameraZoomMessage(zoom_for_round(3)));
-        player_gold.amount = 10 * 20;
+    if let Some(night) = campaign.night(progression.current_round) {
+        debug!("Switching to {}", night.level);
+        *level_selection = LevelSelection::Identifier(night.level.clone());
+        zoom_writer.write(CameraZoomMessage(night.zoom));
+        night.gold.apply(&mut player_gold);
     } else {
         debug!("All levels completed, returning to Title screen");
         screen.set(Screen::Title);
@@ -3,6 +3,10 @@ use crate::prelude::*;
 mod player_gold;
 pub(crate) use player_gold::*;
 
+mod sell;
+pub(crate) use sell::*;
+
 pub(crate) fn plugin(app: &mut App) {
     player_gold::plugin(app);
+    sell::plugin(app);
 }
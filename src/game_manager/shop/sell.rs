@@ -0,0 +1,118 @@
+use bevy::ui::RelativeCursorPosition;
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<SellRefund>();
+    app.add_observer(sell_squad);
+}
+
+/// Share of what a squad is worth that selling it hands back.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct SellRefund {
+    pub rate: f32,
+}
+
+impl Default for SellRefund {
+    fn default() -> Self {
+        Self { rate: 0.5 }
+    }
+}
+
+/// UI a dragged squad can be dropped on to sell it.
+#[derive(Component, Default)]
+#[require(RelativeCursorPosition)]
+pub struct SellZone;
+
+/// Sells a placed player squad, with its memories, for [`SellRefund`] of what it is worth.
+#[derive(Event)]
+pub struct SellSquad {
+    pub squad: Entity,
+}
+
+/// Whether the cursor is over any [`SellZone`].
+pub(crate) fn cursor_over_sell_zone(
+    q_sell_zones: &Query<&RelativeCursorPosition, With<SellZone>>,
+) -> bool {
+    q_sell_zones.iter().any(RelativeCursorPosition::cursor_over)
+}
+
+/// Gold for a squad of `current` out of `max_units` units costing `squad_cost` when full,
+/// carrying memories bought for `memory_price`.
+fn sell_price(
+    squad_cost: u32,
+    current: usize,
+    max_units: usize,
+    memory_price: u32,
+    rate: f32,
+) -> u32 {
+    let units_worth = if max_units == 0 {
+        0.0
+    } else {
+        squad_cost as f32 * current as f32 / max_units as f32
+    };
+    ((units_worth + memory_price as f32) * rate.clamp(0.0, 1.0)).floor() as u32
+}
+
+fn sell_squad(
+    trigger: On<SellSquad>,
+    mut commands: Commands,
+    refund: Res<SellRefund>,
+    unit_stats: Res<UnitStatsCache>,
+    memory_stats: Res<MemoryStatsCache>,
+    mut player_gold: ResMut<PlayerGold>,
+    q_squads: Query<(&Squad, &Transform, Has<BigEyeBuff>, Has<GoldenHeartBuff>), With<PlayerSquad>>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
+) {
+    let Ok((squad, transform, big_eye, golden_heart)) = q_squads.get(trigger.squad) else {
+        return;
+    };
+
+    let squad_cost = unit_stats
+        .stats
+        .get(&squad.child_prefab_name)
+        .map_or(0, |row| row.cost.max(0) as u32);
+    let memory_price = MemoryKind::from_buffs(big_eye, golden_heart)
+        .into_iter()
+        .filter_map(|memory| memory_stats.stats.get(memory.id()))
+        .map(|row| row.price.max(0) as u32)
+        .sum();
+    let price = sell_price(
+        squad_cost,
+        squad.current_unit_count,
+        squad.max_unit_count,
+        memory_price,
+        refund.rate,
+    );
+    player_gold.amount = player_gold.amount.saturating_add(price);
+
+    info!(
+        "Sold {} squad {:?} ({}/{}) for {} gold. Total: {}",
+        squad.child_prefab_name,
+        trigger.squad,
+        squad.current_unit_count,
+        squad.max_unit_count,
+        price,
+        player_gold.amount
+    );
+
+    commands.trigger(VfxEvent::dust(transform.translation.truncate()));
+    commands.trigger(SFXEvent::ui("coin"));
+    commands.entity(trigger.squad).despawn();
+    placement_msg.write(PlacementChangedMessage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squads_sell_for_a_share_of_what_is_left() {
+        // A full 20 gold squad with a 10 gold memory, at half refund
+        assert_eq!(sell_price(20, 50, 50, 10, 0.5), 15);
+        // Half the units are gone, and so is half their worth
+        assert_eq!(sell_price(20, 25, 50, 0, 0.5), 5);
+        assert_eq!(sell_price(20, 25, 50, 0, 1.0), 10);
+    }
+}
@@ -0,0 +1,220 @@
+//! `matchup` command: pits every unit kind against every other in the headless simulation
+//! and writes a win-rate / time-to-kill matrix, then checks the `counter` loop from the CSV.
+//!
+//! ```text
+//! bevy_game matchup [--counts 10,30,50] [--seeds 20] [--units assets/balance/all.unit.csv]
+//!                   [--out matchup.csv | matchup.json]
+//! ```
+
+use anyhow::{Context, bail};
+
+use super::*;
+
+const DEFAULT_OUT: &str = "matchup.csv";
+const DEFAULT_COUNTS: &[usize] = &[10, 30, DEFAULT_SQUAD_SIZE];
+const DEFAULT_SEEDS: u64 = 20;
+
+/// Win rate a unit needs against the kind it counters for the loop to hold.
+const COUNTER_WIN_RATE: f32 = 0.5;
+
+struct MatchupArgs {
+    counts: Vec<usize>,
+    seeds: u64,
+    units: String,
+    out: String,
+}
+
+/// One cell of the matrix: `attacker` squads fighting `defender` squads of the same size.
+#[derive(serde::Serialize, Debug)]
+struct MatchupRow {
+    attacker: UnitKind,
+    defender: UnitKind,
+    count: usize,
+    battles: u64,
+    wins: u64,
+    losses: u64,
+    draws: u64,
+    win_rate: f32,
+    /// Mean seconds the attacker needed to wipe out the defender, over its wins.
+    avg_time_to_kill: Option<f32>,
+    /// Mean surviving attacker units, over its wins.
+    avg_survivors: Option<f32>,
+}
+
+/// Runs the command with the arguments that follow `matchup`.
+pub(super) fn run_command(args: impl Iterator<Item = String>) -> AppExit {
+    match run(args) {
+        Ok(true) => AppExit::Success,
+        Ok(false) => AppExit::error(),
+        Err(error) => {
+            eprintln!("matchup: {error:#}");
+            AppExit::error()
+        }
+    }
+}
+
+/// Returns whether every counter relationship held.
+fn run(args: impl Iterator<Item = String>) -> anyhow::Result<bool> {
+    let args = parse_args(args)?;
+    let stats = load_stats(&args.units)?;
+
+    // Support units can't win a fight on their own, so they only ever draw
+    let kinds: Vec<UnitKind> = stats
+        .kinds()
+        .into_iter()
+        .filter(|kind| {
+            stats
+                .get(kind)
+                .is_some_and(|row| row.attack != AttackStyle::Support)
+        })
+        .collect();
+    let mut rows = Vec::new();
+    for &count in &args.counts {
+        for attacker in &kinds {
+            for defender in &kinds {
+                let row = run_matchup(attacker, defender, count, args.seeds, &stats);
+                eprintln!(
+                    "{:>7} vs {:<7} x{:<3} win {:>5.1}%",
+                    attacker.as_ref(),
+                    defender.as_ref(),
+                    count,
+                    row.win_rate * 100.0
+                );
+                rows.push(row);
+            }
+        }
+    }
+
+    write_rows(&args.out, &rows)?;
+    eprintln!("matchup: wrote {} rows to {}", rows.len(), args.out);
+
+    Ok(check_counter_loop(&rows, &stats))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<MatchupArgs> {
+    let mut parsed = MatchupArgs {
+        counts: DEFAULT_COUNTS.to_vec(),
+        seeds: DEFAULT_SEEDS,
+        units: DEFAULT_UNITS_CSV.to_string(),
+        out: DEFAULT_OUT.to_string(),
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .with_context(|| format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--counts" => {
+                parsed.counts = value
+                    .split(',')
+                    .map(|count| count.trim().parse::<usize>())
+                    .collect::<Result<_, _>>()
+                    .with_context(|| format!("invalid --counts {value}"))?;
+            }
+            "--seeds" => {
+                parsed.seeds = value
+                    .parse()
+                    .with_context(|| format!("invalid --seeds {value}"))?;
+            }
+            "--units" => parsed.units = value,
+            "--out" => parsed.out = value,
+            other => bail!("unknown argument {other}"),
+        }
+    }
+
+    if parsed.counts.is_empty() || parsed.counts.contains(&0) {
+        bail!("--counts needs at least one non-zero squad size");
+    }
+    if parsed.seeds == 0 {
+        bail!("--seeds must be at least 1");
+    }
+
+    Ok(parsed)
+}
+
+fn run_matchup(
+    attacker: &UnitKind,
+    defender: &UnitKind,
+    count: usize,
+    seeds: u64,
+    stats: &UnitStatsCache,
+) -> MatchupRow {
+    let mut row = MatchupRow {
+        attacker: attacker.clone(),
+        defender: defender.clone(),
+        count,
+        battles: seeds,
+        wins: 0,
+        losses: 0,
+        draws: 0,
+        win_rate: 0.0,
+        avg_time_to_kill: None,
+        avg_survivors: None,
+    };
+    let mut total_time = 0.0;
+    let mut total_survivors = 0;
+
+    for seed in 0..seeds {
+        let setup = BattleSetup::facing(
+            &[(attacker.clone(), count)],
+            &[(defender.clone(), count)],
+            seed,
+        );
+        let report = simulate_battle(&setup, stats);
+        match report.outcome {
+            BattleOutcome::PlayerWin => {
+                row.wins += 1;
+                total_time += report.elapsed;
+                total_survivors += report.surviving_units(Faction::Player);
+            }
+            BattleOutcome::EnemyWin => row.losses += 1,
+            BattleOutcome::Draw => row.draws += 1,
+        }
+    }
+
+    row.win_rate = row.wins as f32 / seeds as f32;
+    if row.wins > 0 {
+        row.avg_time_to_kill = Some(total_time / row.wins as f32);
+        row.avg_survivors = Some(total_survivors as f32 / row.wins as f32);
+    }
+    row
+}
+
+fn write_rows(path: &str, rows: &[MatchupRow]) -> anyhow::Result<()> {
+    if path.ends_with(".json") {
+        let json = serde_json::to_string_pretty(rows)?;
+        std::fs::write(path, json).with_context(|| format!("writing {path}"))?;
+    } else {
+        let mut writer = csv::Writer::from_path(path).with_context(|| format!("writing {path}"))?;
+        for row in rows {
+            writer.serialize(row)?;
+        }
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Checks that every unit beats the kind named in its `counter` column, at every count.
+fn check_counter_loop(rows: &[MatchupRow], stats: &UnitStatsCache) -> bool {
+    let mut holds = true;
+    for row in rows {
+        let Some(unit) = stats.get(&row.attacker) else {
+            continue;
+        };
+        if unit.counter.as_ref() != Some(&row.defender) {
+            continue;
+        }
+
+        let passed = row.win_rate > COUNTER_WIN_RATE;
+        holds &= passed;
+        eprintln!(
+            "[{}] {} counters {} at x{}: win {:.1}%",
+            if passed { "PASS" } else { "FAIL" },
+            row.attacker.as_ref(),
+            row.defender.as_ref(),
+            row.count,
+            row.win_rate * 100.0
+        );
+    }
+    holds
+}
@@ -0,0 +1,499 @@
+//! Headless battle simulation for balance testing.
+//!
+//! Builds a `MinimalPlugins` app that contains only the combat systems, so a fight between
+//! two army compositions can be run from a seed without a window, renderer or LDtk world.
+//! The same setup and seed always produce the same report.
+//!
+//! Also backs the `matchup` and `replay` command-line tools:
+//!
+//! ```text
+//! bevy_game matchup [options]
+//! bevy_game replay replays/night_3.json
+//! ```
+
+use std::time::Duration;
+
+use bevy::{state::app::StatesPlugin, time::TimeUpdateStrategy, transform::TransformPlugin};
+
+use crate::{prelude::*, screens::Screen};
+
+mod matchup;
+mod replay;
+
+/// Path of the balance sheet the command-line tools read by default.
+const DEFAULT_UNITS_CSV: &str = "assets/balance/all.unit.csv";
+
+/// Runs a command-line tool if the first argument names one.
+/// Returns `None` for every other invocation so the game starts as usual.
+pub(crate) fn run_command() -> Option<AppExit> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("matchup") => Some(matchup::run_command(args)),
+        Some("replay") => Some(replay::run_command(args)),
+        _ => None,
+    }
+}
+
+fn load_stats(path: &str) -> anyhow::Result<UnitStatsCache> {
+    use anyhow::Context;
+
+    let source = std::fs::read_to_string(path).with_context(|| format!("reading {path}"))?;
+    UnitStatsCache::from_csv_str(&source).with_context(|| format!("parsing {path}"))
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Fixed timestep the simulation advances by each frame.
+pub(crate) const SIMULATION_STEP: f32 = 1.0 / 60.0;
+
+/// Battles still running after this many simulated seconds end in a draw.
+pub(crate) const DEFAULT_TIME_LIMIT: f32 = 180.0;
+
+/// Horizontal distance of each army's squads from the battlefield center.
+const ARMY_OFFSET_X: f32 = 900.0;
+
+/// Vertical spacing between squads of the same army.
+const SQUAD_SPACING_Y: f32 = 700.0;
+
+// ============================================================================
+// Setup
+// ============================================================================
+
+/// One squad to place on the simulated battlefield.
+#[derive(Debug, Clone)]
+pub(crate) struct SquadSetup {
+    pub kind: UnitKind,
+    /// Units in the full squad, which its formation is laid out for.
+    pub count: usize,
+    /// Units lost in earlier nights, out of `count`.
+    pub lost: usize,
+    /// World position of the squad center.
+    pub position: Vec2,
+    pub memories: Vec<MemoryKind>,
+    pub formation: Formation,
+    pub stance: Stance,
+    /// Index into the other army of the squad a [`Stance::Focus`] squad goes for.
+    pub focus: Option<usize>,
+    pub veterancy: Veterancy,
+}
+
+/// Everything needed to run one simulated battle.
+#[derive(Debug, Clone)]
+pub(crate) struct BattleSetup {
+    pub player: Vec<SquadSetup>,
+    pub enemy: Vec<SquadSetup>,
+    pub seed: u64,
+    /// Obstacles and terrain on the field; open ground by default.
+    pub ground: Battleground,
+    /// Simulated seconds before the battle is called a draw.
+    pub time_limit: f32,
+}
+
+impl BattleSetup {
+    /// Lines both armies up facing each other, one squad per `(kind, count)` entry.
+    pub fn facing(player: &[(UnitKind, usize)], enemy: &[(UnitKind, usize)], seed: u64) -> Self {
+        Self {
+            player: line_up(player, -ARMY_OFFSET_X),
+            enemy: line_up(enemy, ARMY_OFFSET_X),
+            seed,
+            ground: Battleground::default(),
+            time_limit: DEFAULT_TIME_LIMIT,
+        }
+    }
+
+    /// The starting layout of a recorded battle.
+    pub fn from_replay(replay: &BattleReplay) -> Self {
+        let mut setup = Self {
+            player: Vec::new(),
+            enemy: Vec::new(),
+            seed: replay.seed,
+            ground: replay.ground.clone(),
+            time_limit: DEFAULT_TIME_LIMIT,
+        };
+
+        // Replays index focus targets into the whole squad list, setups into the other army.
+        let army_index = |index: usize| {
+            let faction = replay.squads.get(index)?.faction;
+            let before = replay.squads[..index]
+                .iter()
+                .filter(|squad| squad.faction == faction)
+                .count();
+            Some(before)
+        };
+
+        for squad in &replay.squads {
+            let squad_setup = SquadSetup {
+                kind: UnitKind::new(&squad.prefab),
+                count: squad.max_unit_count,
+                lost: squad.lost,
+                position: Vec2::from_array(squad.position),
+                memories: squad.memories.clone(),
+                formation: squad.formation,
+                stance: squad.stance,
+                focus: squad.focus.and_then(army_index),
+                veterancy: squad.veterancy,
+            };
+            match squad.faction {
+                Faction::Player => setup.player.push(squad_setup),
+                Faction::Enemy => setup.enemy.push(squad_setup),
+            }
+        }
+
+        setup
+    }
+}
+
+fn line_up(army: &[(UnitKind, usize)], x: f32) -> Vec<SquadSetup> {
+    let offset_y = -((army.len() as f32 - 1.0) * SQUAD_SPACING_Y) / 2.0;
+    army.iter()
+        .enumerate()
+        .map(|(index, (kind, count))| SquadSetup {
+            kind: kind.clone(),
+            count: *count,
+            lost: 0,
+            position: Vec2::new(x, offset_y + index as f32 * SQUAD_SPACING_Y),
+            memories: Vec::new(),
+            formation: Formation::default(),
+            stance: Stance::default(),
+            focus: None,
+            veterancy: Veterancy::default(),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BattleOutcome {
+    PlayerWin,
+    EnemyWin,
+    Draw,
+}
+
+/// How a single squad fared.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SquadReport {
+    pub kind: UnitKind,
+    pub faction: Faction,
+    pub max_units: usize,
+    pub surviving_units: usize,
+    /// Sum of the current health of all surviving units.
+    pub surviving_health: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BattleReport {
+    pub outcome: BattleOutcome,
+    /// Simulated seconds until one side was wiped out or the frame budget ran out.
+    pub elapsed: f32,
+    pub squads: Vec<SquadReport>,
+}
+
+impl BattleReport {
+    pub fn surviving_units(&self, faction: Faction) -> usize {
+        self.squads
+            .iter()
+            .filter(|squad| squad.faction == faction)
+            .map(|squad| squad.surviving_units)
+            .sum()
+    }
+}
+
+// ============================================================================
+// Simulation
+// ============================================================================
+
+/// Runs a battle to completion and reports the result.
+///
+/// Panics if a squad's kind has no row in `stats`.
+pub(crate) fn simulate_battle(setup: &BattleSetup, stats: &UnitStatsCache) -> BattleReport {
+    let max_frames = (setup.time_limit / SIMULATION_STEP).ceil() as u32;
+    run_battle(setup, stats, max_frames, |_, _| {})
+}
+
+/// Re-runs a recorded battle with its original frame timings and speed changes.
+pub(crate) fn replay_battle(
+    replay: &BattleReplay,
+    stats: &UnitStatsCache,
+) -> anyhow::Result<BattleReport> {
+    let setup = BattleSetup::from_replay(replay);
+    if let Some(squad) = setup
+        .player
+        .iter()
+        .chain(&setup.enemy)
+        .find(|squad| stats.get(&squad.kind).is_none())
+    {
+        anyhow::bail!("unknown unit kind {}", squad.kind);
+    }
+    let max_frames = replay.frame_deltas_ns.len() as u32;
+
+    Ok(run_battle(&setup, stats, max_frames, |app, frame| {
+        let delta = Duration::from_nanos(replay.frame_deltas_ns[frame as usize] as u64);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+
+        for change in replay
+            .speed_changes
+            .iter()
+            .filter(|change| change.frame == frame)
+        {
+            app.world_mut()
+                .resource_mut::<Time<Virtual>>()
+                .set_relative_speed(change.relative_speed);
+        }
+    }))
+}
+
+/// Runs the battle for at most `max_frames`, calling `before_frame` ahead of every update.
+fn run_battle(
+    setup: &BattleSetup,
+    stats: &UnitStatsCache,
+    max_frames: u32,
+    before_frame: impl FnMut(&mut App, u32),
+) -> BattleReport {
+    let mut app = build_app(setup, stats);
+    fight(&mut app, setup, stats, max_frames, before_frame)
+}
+
+/// Places both armies in an app from [`build_app`] and fights for at most `max_frames`,
+/// calling `before_frame` ahead of every update.
+pub(crate) fn fight(
+    app: &mut App,
+    setup: &BattleSetup,
+    stats: &UnitStatsCache,
+    max_frames: u32,
+    mut before_frame: impl FnMut(&mut App, u32),
+) -> BattleReport {
+    let squads: Vec<(Entity, &SquadSetup, Faction)> = setup
+        .player
+        .iter()
+        .map(|squad| (squad, Faction::Player))
+        .chain(setup.enemy.iter().map(|squad| (squad, Faction::Enemy)))
+        .map(|(squad, faction)| {
+            let entity = spawn_squad(app.world_mut(), stats, squad, faction);
+            (entity, squad, faction)
+        })
+        .collect();
+
+    // Focus targets name squads of the other army, so they are set once every squad exists.
+    for &(entity, squad, faction) in &squads {
+        let Some(focus) = squad.focus else {
+            continue;
+        };
+        let target = squads
+            .iter()
+            .filter(|(_, _, other)| *other != faction)
+            .nth(focus)
+            .map(|(target, _, _)| *target);
+        if let Some(mut spawned) = app.world_mut().get_mut::<Squad>(entity) {
+            spawned.focus = target;
+        }
+    }
+
+    // One frame of preparation spawns the units and places them, as the game does.
+    app.update();
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Battle);
+
+    let start = app.world().resource::<Time<Virtual>>().elapsed_secs();
+    let mut frame = 0;
+    let outcome = loop {
+        if frame >= max_frames {
+            break BattleOutcome::Draw;
+        }
+        before_frame(app, frame);
+        app.update();
+        frame += 1;
+
+        let (player_alive, enemy_alive) = count_alive(app.world_mut());
+        match (player_alive, enemy_alive) {
+            (0, 0) => break BattleOutcome::Draw,
+            (_, 0) => break BattleOutcome::PlayerWin,
+            (0, _) => break BattleOutcome::EnemyWin,
+            _ => {}
+        }
+    };
+
+    BattleReport {
+        outcome,
+        elapsed: app.world().resource::<Time<Virtual>>().elapsed_secs() - start,
+        squads: squads
+            .into_iter()
+            .map(|(entity, squad, faction)| squad_report(app.world_mut(), entity, squad, faction))
+            .collect(),
+    }
+}
+
+/// Builds an app with only the combat plugins on the setup's ground, still in
+/// `GameState::Preparing`.
+pub(crate) fn build_app(setup: &BattleSetup, stats: &UnitStatsCache) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, TransformPlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        SIMULATION_STEP,
+    )));
+
+    app.insert_state(Screen::Gameplay);
+    configure_game_state(&mut app);
+    super::army::headless_plugin(&mut app);
+    super::balance::headless_plugin(&mut app);
+    super::memory::headless_plugin(&mut app);
+    super::land::headless_plugin(&mut app);
+
+    app.insert_resource(stats.clone());
+    app.insert_resource(BattleRng::from_seed(setup.seed));
+    setup.ground.apply(app.world_mut());
+
+    app
+}
+
+fn spawn_squad(
+    world: &mut World,
+    stats: &UnitStatsCache,
+    setup: &SquadSetup,
+    faction: Faction,
+) -> Entity {
+    assert!(
+        stats.get(&setup.kind).is_some(),
+        "no unit definition for {}",
+        setup.kind
+    );
+    let squad = Squad::new(setup.kind.as_ref().to_string(), setup.count)
+        .with_survivors(setup.count.saturating_sub(setup.lost))
+        .with_formation(setup.formation)
+        .with_stance(setup.stance);
+
+    // The units are left to the game's own spawning on the next update, so they take
+    // the same formation slots, transforms and parents as in a live battle.
+    let squad_entity = world
+        .spawn((
+            SquadLossTracker::for_losses(squad.loss_percentage()),
+            squad,
+            setup.veterancy,
+            faction,
+            SquadOriginPosition(setup.position),
+            Transform::from_translation(setup.position.extend(0.0)),
+            Name::new(format!("{}_Squad", setup.kind.as_ref())),
+        ))
+        .id();
+
+    for memory in &setup.memories {
+        memory.insert_buff(&mut world.commands().entity(squad_entity));
+    }
+    world.flush();
+
+    squad_entity
+}
+
+fn count_alive(world: &mut World) -> (usize, usize) {
+    let mut query = world.query::<(&Faction, &Health)>();
+    query
+        .iter(world)
+        .filter(|(_, health)| health.is_alive())
+        .fold((0, 0), |(player, enemy), (faction, _)| match faction {
+            Faction::Player => (player + 1, enemy),
+            Faction::Enemy => (player, enemy + 1),
+        })
+}
+
+fn squad_report(
+    world: &mut World,
+    squad_entity: Entity,
+    setup: &SquadSetup,
+    faction: Faction,
+) -> SquadReport {
+    let mut q_units = world.query::<(&BelongToSquad, &Health)>();
+    let (surviving_units, surviving_health) = q_units
+        .iter(world)
+        .filter(|(belong_to, health)| belong_to.0 == squad_entity && health.is_alive())
+        .fold((0, 0.0), |(count, total), (_, health)| {
+            (count + 1, total + health.get_current())
+        });
+
+    SquadReport {
+        kind: setup.kind.clone(),
+        faction,
+        max_units: setup.count,
+        surviving_units,
+        surviving_health,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> UnitStatsCache {
+        UnitStatsCache::from_csv_str(include_str!("../../../assets/balance/all.unit.csv"))
+            .expect("all.unit.csv should parse")
+    }
+
+    #[test]
+    fn same_seed_gives_same_report() {
+        let setup = BattleSetup::facing(
+            &[(UnitKind::new("Shield"), 20), (UnitKind::new("Archer"), 20)],
+            &[(UnitKind::new("Spear"), 20), (UnitKind::new("Cavalry"), 20)],
+            7,
+        );
+        let stats = stats();
+
+        let first = simulate_battle(&setup, &stats);
+        let second = simulate_battle(&setup, &stats);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn wiped_out_side_has_no_survivors() {
+        let setup = BattleSetup::facing(
+            &[(UnitKind::new("Shield"), 30)],
+            &[(UnitKind::new("Archer"), 5)],
+            1,
+        );
+
+        let report = simulate_battle(&setup, &stats());
+
+        assert_eq!(report.outcome, BattleOutcome::PlayerWin);
+        assert_eq!(report.surviving_units(Faction::Enemy), 0);
+        assert!(report.surviving_units(Faction::Player) > 0);
+    }
+
+    #[test]
+    fn formation_changes_the_battle() {
+        let stats = stats();
+        let mut setup = BattleSetup::facing(
+            &[(UnitKind::new("Cavalry"), 30)],
+            &[(UnitKind::new("Archer"), 30)],
+            5,
+        );
+        let column = simulate_battle(&setup, &stats);
+
+        setup.player[0].formation = Formation::Wedge;
+        let wedge = simulate_battle(&setup, &stats);
+
+        assert_ne!(column, wedge);
+    }
+
+    #[test]
+    fn holding_armies_never_meet() {
+        let mut setup = BattleSetup::facing(
+            &[(UnitKind::new("Shield"), 10)],
+            &[(UnitKind::new("Spear"), 10)],
+            2,
+        );
+        setup.time_limit = 10.0;
+        for squad in setup.player.iter_mut().chain(&mut setup.enemy) {
+            squad.stance = Stance::HoldPosition;
+        }
+
+        let report = simulate_battle(&setup, &stats());
+
+        assert_eq!(report.outcome, BattleOutcome::Draw);
+        assert_eq!(report.surviving_units(Faction::Player), 10);
+        assert_eq!(report.surviving_units(Faction::Enemy), 10);
+    }
+}
@@ -0,0 +1,58 @@
+//! `replay` command: re-simulates a battle recorded during play and prints how it ended.
+//!
+//! ```text
+//! bevy_game replay replays/night_3.json [--units assets/balance/all.unit.csv]
+//! ```
+
+use anyhow::{Context, bail};
+
+use super::*;
+
+/// Runs the command with the arguments that follow `replay`.
+pub(super) fn run_command(args: impl Iterator<Item = String>) -> AppExit {
+    match run(args) {
+        Ok(()) => AppExit::Success,
+        Err(error) => {
+            eprintln!("replay: {error:#}");
+            AppExit::error()
+        }
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let path = args.next().context("missing replay file")?;
+    let mut units = DEFAULT_UNITS_CSV.to_string();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .with_context(|| format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--units" => units = value,
+            other => bail!("unknown argument {other}"),
+        }
+    }
+
+    let replay = load_replay(&path)?;
+    let stats = load_stats(&units)?;
+    let report = replay_battle(&replay, &stats)?;
+
+    eprintln!(
+        "round {} seed {}: {:?} after {:.1}s ({} frames recorded)",
+        replay.round,
+        replay.seed,
+        report.outcome,
+        report.elapsed,
+        replay.frame_deltas_ns.len()
+    );
+    for squad in &report.squads {
+        eprintln!(
+            "{:?} {:>7}: {}/{} alive, {:.0} hp",
+            squad.faction,
+            squad.kind.as_ref(),
+            squad.surviving_units,
+            squad.max_units,
+            squad.surviving_health
+        );
+    }
+    Ok(())
+}
@@ -14,8 +14,11 @@ pub struct PlayerUnitCountMarker;
 #[derive(Component)]
 pub struct EnemyUnitCountMarker;
 
-// #[derive(Component)]
-// pub struct MoraleBarMarker;
+#[derive(Component)]
+pub struct PlayerMoraleBarMarker;
+
+#[derive(Component)]
+pub struct EnemyMoraleBarMarker;
 
 /// Tracks the initial unit counts at the start of battle
 #[derive(Resource, Default)]
@@ -32,7 +35,12 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
         )
         .add_systems(
             Update,
-            (record_initial_counts, update_battle_bars).run_if(in_state(GameState::Battle)),
+            (
+                record_initial_counts,
+                update_battle_bars,
+                update_morale_bars,
+            )
+                .run_if(in_state(GameState::Battle)),
         );
 }
 
@@ -299,54 +307,49 @@ fn spawn_top_middle_ui(
                                         ));
                                     });
 
-                                // // Morale label
-                                // parent
-                                //     .spawn(Node {
-                                //         flex_direction: FlexDirection::Row,
-                                //         justify_content: JustifyContent::Center,
-                                //         width: percent(100),
-                                //         margin: UiRect::axes(px(0), px(8)).with_bottom(px(4)),
-                                //         ..default()
-                                //     })
-                                //     .with_children(|parent| {
-                                //         parent.spawn((
-                                //             Text::new("SAINTY"),
-                                //             TextFont {
-                                //                 font: font.clone(),
-                                //                 font_size: 11.0,
-                                //                 ..default()
-                                //             },
-                                //             TextColor(Color::srgb(0.541, 0.541, 0.667)), // #8a8aaa
-                                //         ));
-                                //     });
-
-                                // // Morale bar container
-                                // parent
-                                //     .spawn((
-                                //         Node {
-                                //             position_type: PositionType::Relative,
-                                //             width: percent(100),
-                                //             height: px(12),
-                                //             border: UiRect::all(px(1)),
-                                //             border_radius: BorderRadius::all(px(6)),
-                                //             overflow: Overflow::clip(),
-                                //             ..default()
-                                //         },
-                                //         BackgroundColor(Color::srgb(0.039, 0.039, 0.078)), // #0a0a14
-                                //         BorderColor::all(Color::srgb(0.165, 0.165, 0.290)), // #2a2a4a
-                                //     ))
-                                //     .with_children(|parent| {
-                                //         // Fill bar (starts at 50% for neutral)
-                                //         parent.spawn((
-                                //             Node {
-                                //                 height: percent(100),
-                                //                 width: percent(50),
-                                //                 ..default()
-                                //             },
-                                //             BackgroundColor(Color::srgb(0.416, 0.416, 0.667)), // #6a6aaa
-                                //             MoraleBarMarker,
-                                //         ));
-                                //     });
+                                // Morale label
+                                parent
+                                    .spawn(Node {
+                                        flex_direction: FlexDirection::Row,
+                                        justify_content: JustifyContent::Center,
+                                        width: percent(100),
+                                        margin: UiRect::axes(px(0), px(8)).with_bottom(px(4)),
+                                        ..default()
+                                    })
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new("MORALE"),
+                                            TextFont {
+                                                font: font.clone(),
+                                                font_size: 11.0,
+                                                ..default()
+                                            },
+                                            TextColor(palette.brown_dark.with_alpha(0.85)),
+                                        ));
+                                    });
+
+                                // Morale bars, one per side, filling outward like the unit bars
+                                parent
+                                    .spawn(Node {
+                                        flex_direction: FlexDirection::Row,
+                                        justify_content: JustifyContent::SpaceBetween,
+                                        width: percent(100),
+                                        ..default()
+                                    })
+                                    .with_children(|parent| {
+                                        spawn_morale_bar(
+                                            parent,
+                                            JustifyContent::FlexEnd,
+                                            palette.blue_medium,
+                                            PlayerMoraleBarMarker,
+                                        );
+                                        spawn_morale_bar(
+                                            parent,
+                                            JustifyContent::FlexStart,
+                                            palette.brown_reddish,
+                                            EnemyMoraleBarMarker,
+                                        );
+                                    });
                             });
                     });
             });
@@ -429,30 +432,79 @@ fn update_battle_bars(
     }
 }
 
-// /// Update the morale bar based on CombatFlux
-// fn update_morale_bar(
-//     combat_flux: Res<crate::game_manager::army::CombatFlux>,
-//     mut q_morale_bar: Query<(&mut Node, &mut BackgroundColor), With<MoraleBarMarker>>,
-// ) {
-//     let Ok((mut node, mut bg_color)) = q_morale_bar.single_mut() else {
-//         return;
-//     };
-
-//     let morale = combat_flux.morale();
-
-//     // Convert morale from [-100, 100] range to [0, 100] percentage
-//     // -100 = 0%, 0 = 50%, +100 = 100%
-//     let fill_percent = ((morale + 100.0) / 200.0) * 100.0;
-
-//     // Set color based on which side has advantage
-//     let color = if morale < -5.0 {
-//         Color::srgb(0.937, 0.267, 0.267) // Red for enemy advantage (#ef4444)
-//     } else if morale > 5.0 {
-//         Color::srgb(0.231, 0.510, 0.965) // Blue for player advantage (#3b82f6)
-//     } else {
-//         Color::srgb(0.416, 0.416, 0.667) // Neutral purple (#6a6aaa)
-//     };
-
-//     node.width = Val::Percent(fill_percent);
-//     *bg_color = BackgroundColor(color);
-// }
+fn spawn_morale_bar(
+    parent: &mut ChildSpawnerCommands,
+    justify_content: JustifyContent,
+    color: Color,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                justify_content,
+                width: px(176),
+                height: px(10),
+                border: UiRect::all(px(1)),
+                border_radius: BorderRadius::all(px(5)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.15)),
+            BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.20)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    height: percent(100),
+                    width: percent(100),
+                    border_radius: BorderRadius::all(px(5)),
+                    ..default()
+                },
+                BackgroundColor(color),
+                marker,
+            ));
+        });
+}
+
+/// Update the morale bars with each side's morale, weighted by how many units each squad has left
+fn update_morale_bars(
+    palette: Res<ColorPalette>,
+    q_squads: Query<(&Squad, &SquadMorale, &Faction)>,
+    mut q_player_bar: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<PlayerMoraleBarMarker>, Without<EnemyMoraleBarMarker>),
+    >,
+    mut q_enemy_bar: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<EnemyMoraleBarMarker>, Without<PlayerMoraleBarMarker>),
+    >,
+) {
+    let side_morale = |side: Faction| {
+        let (total, units) = q_squads
+            .iter()
+            .filter(|(squad, _, faction)| **faction == side && squad.current_unit_count > 0)
+            .fold((0.0, 0.0), |(total, units), (squad, morale, _)| {
+                let count = squad.current_unit_count as f32;
+                (total + morale.value() * count, units + count)
+            });
+        if units > 0.0 { total / units } else { 0.0 }
+    };
+    let morale_color = |morale: f32, steady_color: Color| match MoraleState::for_value(morale) {
+        MoraleState::Steady | MoraleState::Rallied => steady_color,
+        MoraleState::Wavering => palette.green_yellow,
+        MoraleState::Routing => palette.tan_medium,
+    };
+
+    if let Ok((mut node, mut background)) = q_player_bar.single_mut() {
+        let morale = side_morale(Faction::Player);
+        node.width = Val::Percent(morale / MAX_MORALE * 100.0);
+        background.0 = morale_color(morale, palette.blue_medium);
+    }
+
+    if let Ok((mut node, mut background)) = q_enemy_bar.single_mut() {
+        let morale = side_morale(Faction::Enemy);
+        node.width = Val::Percent(morale / MAX_MORALE * 100.0);
+        background.0 = morale_color(morale, palette.brown_reddish);
+    }
+}
@@ -34,12 +34,24 @@ fn spawn_bottom_left_ui(
     asset_server: Res<AssetServer>,
     root_query: Query<Entity, With<PrepareRootNode>>,
     game_progress: Res<GameProgress>,
+    campaign: Res<Campaign>,
     palette: Res<ColorPalette>,
 ) {
-    // Only show memory panel from round 2 onwards
-    if game_progress.current_round < 2 && std::env::var("ENABLE_MEMORY").is_err() {
+    // Only show the memories the night sells, and no panel if it sells none
+    let night = campaign.night(game_progress.current_round);
+    let offers = |memory| {
+        std::env::var("ENABLE_MEMORY").is_ok() || night.is_some_and(|night| night.offers(memory))
+    };
+    if !MemoryKind::ALL.into_iter().any(&offers) {
         return;
     }
+    let display = |memory| {
+        if offers(memory) {
+            Display::Flex
+        } else {
+            Display::None
+        }
+    };
 
     let Ok(root_entity) = root_query.single() else {
         warn!("PrepareRootNode not found for bottom left UI");
@@ -78,6 +90,7 @@ fn spawn_bottom_left_ui(
                             .spawn((
                                 Button,
                                 Node {
+                                    display: display(MemoryKind::BigEye),
                                     width: Val::Px(88.0),
                                     height: Val::Px(88.0),
                                     padding: UiRect::all(Val::Px(16.0)),
@@ -110,6 +123,7 @@ fn spawn_bottom_left_ui(
                             .spawn((
                                 Button,
                                 Node {
+                                    display: display(MemoryKind::GoldenHeart),
                                     width: Val::Px(88.0),
                                     height: Val::Px(88.0),
                                     padding: UiRect::all(Val::Px(16.0)),
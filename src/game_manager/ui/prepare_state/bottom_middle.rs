@@ -1,8 +1,11 @@
 use crate::game_manager::balance::UnitStatsCache;
 use crate::game_manager::scene::InBoundary;
-use crate::game_manager::shop::{PlayerGold, PlayerGoldNotEnoughMessage};
+use crate::game_manager::shop::{
+    PlayerGold, PlayerGoldNotEnoughMessage, SellZone, cursor_over_sell_zone,
+};
 use crate::game_manager::{DEFAULT_SQUAD_SIZE, spawn_player_squad};
 use crate::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 use bevy_tweening::{
     lens::{UiTransformRotationLens, UiTransformScaleLens},
     *,
@@ -11,14 +14,9 @@ use std::time::Duration;
 
 use super::root::{PrepareRootNode, PrepareUiSets};
 
-#[derive(Component, Default)]
-pub(crate) struct ShieldButtonMarker;
-
-#[derive(Component, Default)]
-pub(crate) struct ArcherButtonMarker;
-
-#[derive(Component, Default)]
-pub(crate) struct SpearButtonMarker;
+/// Shop button that buys a squad of its unit.
+#[derive(Component)]
+pub(crate) struct UnitButton(pub UnitKind);
 
 /// Marker component for squads that are being dragged and should follow cursor
 #[derive(Component)]
@@ -46,16 +44,34 @@ fn spawn_bottom_middle_ui(
     asset_server: Res<AssetServer>,
     root_query: Query<Entity, With<PrepareRootNode>>,
     palette: Res<ColorPalette>,
+    unit_stats: Res<UnitStatsCache>,
+    progress: Res<GameProgress>,
+    campaign: Res<Campaign>,
 ) {
     let Ok(root_entity) = root_query.single() else {
         warn!("PrepareRootNode not found for bottom middle UI");
         return;
     };
 
-    // Load unit images
-    let shield_img = asset_server.load("procreate/Shield.png");
-    let archer_img = asset_server.load("procreate/Archer.png");
-    let spear_img = asset_server.load("procreate/Spear.png");
+    // A button per unit the night sells, in its order; the whole sheet past the last night
+    let units: Vec<_> = match campaign.night(progress.current_round) {
+        Some(night) => night
+            .units
+            .iter()
+            .filter_map(|id| {
+                let row = unit_stats.stats.get(id);
+                if row.is_none() {
+                    warn!("Night sells unknown unit '{}'", id);
+                }
+                row
+            })
+            .collect(),
+        None => {
+            let mut units: Vec<_> = unit_stats.stats.values().collect();
+            units.sort_by(|a, b| a.id.cmp(&b.id));
+            units
+        }
+    };
 
     commands.entity(root_entity).with_children(|parent| {
         // Bottom-middle container
@@ -72,114 +88,55 @@ fn spawn_bottom_middle_ui(
                 Name::new("Bottom Middle UI"),
             ))
             .with_children(|parent| {
-                // Button container
+                // Button container, which squads are dropped back on to be sold
                 parent
-                    .spawn(Node {
-                        flex_direction: FlexDirection::Row,
-                        column_gap: Val::Px(16.0), // HIG: 8pt grid (16pt between buttons)
-                        padding: UiRect::all(Val::Px(16.0)), // HIG: 16pt standard padding
-                        border: UiRect::all(Val::Px(1.0)),
-                        border_radius: BorderRadius::all(Val::Px(20.0)), // HIG: 20pt for cards
-                        ..default()
-                    })
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(16.0), // HIG: 8pt grid (16pt between buttons)
+                            padding: UiRect::all(Val::Px(16.0)), // HIG: 16pt standard padding
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(20.0)), // HIG: 20pt for cards
+                            ..default()
+                        },
+                        SellZone,
+                    ))
                     .insert(BackgroundColor(palette.blue_dark.with_alpha(0.5)))
                     .insert(BorderColor::all(palette.purple_lighter.with_alpha(0.40)))
                     .with_children(|parent| {
-                        // Spear button
-                        parent
-                            .spawn((
-                                Button,
-                                Node {
-                                    width: Val::Px(88.0),
-                                    height: Val::Px(88.0),
-                                    padding: UiRect::all(Val::Px(16.0)),
-                                    margin: UiRect::vertical(Val::Px(4.0)),
-                                    border_radius: BorderRadius::all(Val::Px(12.0)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                UiTransform::default(),
-                                SpearButtonMarker,
-                            ))
-                            .with_children(|parent| {
-                                parent.spawn((
-                                    ImageNode {
-                                        image: spear_img.clone(),
-                                        ..default()
-                                    },
-                                    Node {
-                                        width: Val::Percent(55.0),
-                                        height: Val::Auto,
-                                        ..default()
-                                    },
-                                ));
-                            });
-
-                        // Shield button
-                        parent
-                            .spawn((
-                                Button,
-                                Node {
-                                    width: Val::Px(88.0),
-                                    height: Val::Px(88.0),
-                                    padding: UiRect::all(Val::Px(16.0)),
-                                    margin: UiRect::vertical(Val::Px(4.0)),
-                                    border_radius: BorderRadius::all(Val::Px(12.0)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                UiTransform::default(),
-                                ShieldButtonMarker,
-                            ))
-                            .with_children(|parent| {
-                                parent.spawn((
-                                    ImageNode {
-                                        image: shield_img.clone(),
-                                        ..default()
-                                    },
+                        for unit in units {
+                            parent
+                                .spawn((
+                                    Button,
                                     Node {
-                                        width: Val::Percent(70.0),
-                                        height: Val::Auto,
+                                        width: Val::Px(88.0),
+                                        height: Val::Px(88.0),
+                                        padding: UiRect::all(Val::Px(16.0)),
+                                        margin: UiRect::vertical(Val::Px(4.0)),
+                                        border_radius: BorderRadius::all(Val::Px(12.0)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
                                         ..default()
                                     },
-                                ));
-                            });
-
-                        // Archer button
-                        parent
-                            .spawn((
-                                Button,
-                                Node {
-                                    width: Val::Px(88.0),
-                                    height: Val::Px(88.0),
-                                    padding: UiRect::all(Val::Px(16.0)),
-                                    margin: UiRect::vertical(Val::Px(4.0)),
-                                    border_radius: BorderRadius::all(Val::Px(12.0)),
-                                    justify_content: JustifyContent::Center,
-                                    align_items: AlignItems::Center,
-                                    ..default()
-                                },
-                                BackgroundColor(Color::NONE),
-                                UiTransform::default(),
-                                ArcherButtonMarker,
-                            ))
-                            .with_children(|parent| {
-                                parent.spawn((
-                                    ImageNode {
-                                        image: archer_img.clone(),
-                                        ..default()
-                                    },
-                                    Node {
-                                        width: Val::Percent(80.0),
-                                        height: Val::Auto,
-                                        ..default()
-                                    },
-                                ));
-                            });
+                                    BackgroundColor(Color::NONE),
+                                    UiTransform::default(),
+                                    UnitButton(UnitKind::new(&unit.id)),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        ImageNode {
+                                            image: asset_server
+                                                .load(format!("procreate/{}.png", unit.sprite)),
+                                            ..default()
+                                        },
+                                        Node {
+                                            width: Val::Auto,
+                                            height: Val::Percent(100.0),
+                                            ..default()
+                                        },
+                                    ));
+                                });
+                        }
                     });
             });
     });
@@ -187,9 +144,7 @@ fn spawn_bottom_middle_ui(
 
 fn handle_button_interaction(
     mut commands: Commands,
-    shield_q: Query<(Entity, &Interaction), (Changed<Interaction>, With<ShieldButtonMarker>)>,
-    archer_q: Query<(Entity, &Interaction), (Changed<Interaction>, With<ArcherButtonMarker>)>,
-    spear_q: Query<(Entity, &Interaction), (Changed<Interaction>, With<SpearButtonMarker>)>,
+    button_q: Query<(Entity, &Interaction, &UnitButton), Changed<Interaction>>,
     unit_stats: Res<UnitStatsCache>,
     mut player_gold: ResMut<PlayerGold>,
     window_q: Query<&Window>,
@@ -203,43 +158,11 @@ fn handle_button_interaction(
         return;
     };
 
-    for (entity, interaction) in shield_q.iter() {
-        if *interaction == Interaction::Pressed {
-            try_spawn_unit(
-                &mut commands,
-                UnitKind::Shield,
-                entity,
-                &unit_stats,
-                &mut player_gold,
-                window,
-                camera,
-                camera_transform,
-                &mut not_enough_gold_msg,
-            );
-        }
-    }
-
-    for (entity, interaction) in archer_q.iter() {
-        if *interaction == Interaction::Pressed {
-            try_spawn_unit(
-                &mut commands,
-                UnitKind::Archer,
-                entity,
-                &unit_stats,
-                &mut player_gold,
-                window,
-                camera,
-                camera_transform,
-                &mut not_enough_gold_msg,
-            );
-        }
-    }
-
-    for (entity, interaction) in spear_q.iter() {
+    for (entity, interaction, button) in button_q.iter() {
         if *interaction == Interaction::Pressed {
             try_spawn_unit(
                 &mut commands,
-                UnitKind::Spear,
+                button.0.clone(),
                 entity,
                 &unit_stats,
                 &mut player_gold,
@@ -298,7 +221,11 @@ fn try_spawn_unit(
         unit_type, world_pos, unit_row.cost, player_gold.amount
     );
 
-    let squad_id = spawn_player_squad(commands, unit_type.as_ref(), world_pos, DEFAULT_SQUAD_SIZE);
+    let squad_id = spawn_player_squad(
+        commands,
+        Squad::new(unit_type.as_ref().to_string(), DEFAULT_SQUAD_SIZE),
+        world_pos,
+    );
     commands.entity(squad_id).insert(FollowingCursor);
 }
 
@@ -335,13 +262,15 @@ fn handle_mouse_release(
     mut commands: Commands,
     in_boundary: Res<InBoundary>,
     following_q: Query<(Entity, &Transform, &Squad), With<FollowingCursor>>,
+    q_sell_zones: Query<&RelativeCursorPosition, With<SellZone>>,
     unit_stats: Res<UnitStatsCache>,
     mut player_gold: ResMut<PlayerGold>,
+    mut placement_msg: MessageWriter<PlacementChangedMessage>,
 ) {
     if mouse.just_released(MouseButton::Left) {
         for (entity, transform, squad) in &following_q {
-            if !in_boundary.0 {
-                // Out of boundary: refund gold and despawn
+            if !in_boundary.0 || cursor_over_sell_zone(&q_sell_zones) {
+                // Out of boundary or back on the shop: refund gold and despawn
                 if let Some(unit_row) = unit_stats.stats.get(&squad.child_prefab_name) {
                     player_gold.amount = player_gold.amount.saturating_add(unit_row.cost as u32);
                     info!(
@@ -367,6 +296,7 @@ fn handle_mouse_release(
                 RunWithNoModel,
                 SquadOriginPosition(world_pos),
             ));
+            placement_msg.write(PlacementChangedMessage);
         }
     }
 }
@@ -376,14 +306,7 @@ fn handle_button_hover(
     palette: Res<ColorPalette>,
     mut button_q: Query<
         (Entity, &Interaction, &UiTransform, &mut BackgroundColor),
-        (
-            Changed<Interaction>,
-            Or<(
-                With<ShieldButtonMarker>,
-                With<ArcherButtonMarker>,
-                With<SpearButtonMarker>,
-            )>,
-        ),
+        (Changed<Interaction>, With<UnitButton>),
     >,
 ) {
     for (entity, interaction, transform, mut bg) in button_q.iter_mut() {
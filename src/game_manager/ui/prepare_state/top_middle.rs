@@ -34,6 +34,7 @@ fn spawn_top_middle_ui(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     root_query: Query<Entity, With<PrepareRootNode>>,
+    campaign: Res<Campaign>,
 ) {
     let Ok(root_entity) = root_query.single() else {
         return;
@@ -122,8 +123,8 @@ fn spawn_top_middle_ui(
                                 ..default()
                             })
                             .with_children(|parent| {
-                                // Spawn all 3 battle dots
-                                for i in 0..3 {
+                                // One battle dot per night of the campaign
+                                for i in 0..campaign.nights.len() {
                                     parent.spawn((
                                         Node {
                                             width: Val::Px(16.0), // HIG: 16pt (8pt grid)
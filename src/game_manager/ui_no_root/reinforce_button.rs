@@ -0,0 +1,142 @@
+use bevy::ui::Val::*;
+use bevy_ui_anchor::prelude::*;
+
+use crate::prelude::*;
+
+pub(crate) fn plugin(app: &mut bevy::app::App) {
+    app.add_systems(
+        Update,
+        (
+            spawn_reinforce_buttons,
+            update_reinforce_buttons,
+            handle_reinforce_click,
+        )
+            .run_if(in_state(GameState::Preparing)),
+    );
+}
+
+/// Button over a damaged player squad that buys back its missing units.
+#[derive(Component)]
+struct ReinforceButton {
+    squad: Entity,
+}
+
+/// Marker for the button's price text
+#[derive(Component)]
+struct ReinforceTextMarker;
+
+/// Gives every damaged player squad without one a reinforce button.
+fn spawn_reinforce_buttons(
+    q_squads: Query<(Entity, &Squad), With<PlayerSquad>>,
+    q_buttons: Query<&ReinforceButton>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    palette: Res<ColorPalette>,
+) {
+    for (squad_entity, squad) in &q_squads {
+        if squad.current_unit_count >= squad.max_unit_count {
+            continue;
+        }
+        if q_buttons.iter().any(|button| button.squad == squad_entity) {
+            continue;
+        }
+
+        let font = asset_server.load("fonts/Quicksand-Regular.ttf");
+        commands
+            .spawn((
+                Button,
+                ReinforceButton {
+                    squad: squad_entity,
+                },
+                Node {
+                    padding: UiRect::axes(Px(12.0), Px(4.0)),
+                    border: UiRect::all(Px(1.0)),
+                    border_radius: BorderRadius::all(Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(palette.blue_darkest.with_alpha(0.85)),
+                BorderColor::all(palette.green_light.with_alpha(0.6)),
+                Name::new("Reinforce Button"),
+                AnchorUiNode::to_entity(squad_entity),
+                AnchorUiConfig {
+                    anchorpoint: AnchorPoint::bottommid(),
+                    ..Default::default()
+                },
+                DespawnOnExit(GameState::Preparing),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new("Reinforce"),
+                    TextFont {
+                        font,
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(palette.get(UiColorName::LabelText)),
+                    ReinforceTextMarker,
+                ));
+            });
+    }
+}
+
+/// Keeps the price on each button current, and drops the buttons of squads that are
+/// full or gone.
+fn update_reinforce_buttons(
+    q_buttons: Query<(Entity, &ReinforceButton, &Children)>,
+    q_squads: Query<&Squad, With<PlayerSquad>>,
+    mut q_text: Query<(&mut Text, &mut TextColor), With<ReinforceTextMarker>>,
+    unit_stats: Res<UnitStatsCache>,
+    player_gold: Res<PlayerGold>,
+    palette: Res<ColorPalette>,
+    mut commands: Commands,
+) {
+    for (button_entity, button, children) in &q_buttons {
+        let Ok(squad) = q_squads.get(button.squad) else {
+            commands.entity(button_entity).despawn();
+            continue;
+        };
+        if squad.current_unit_count >= squad.max_unit_count {
+            commands.entity(button_entity).despawn();
+            continue;
+        }
+        let Some(definition) = unit_stats.stats.get(&squad.child_prefab_name) else {
+            continue;
+        };
+
+        let offer = Reinforcement::offer(squad, definition.cost.max(0) as u32, player_gold.amount);
+        let (label, color) = if offer.units == 0 {
+            (
+                format!("Reinforce {}g", offer.full_cost),
+                palette.brown_reddish,
+            )
+        } else {
+            (
+                format!("Reinforce +{} for {}g", offer.units, offer.cost),
+                palette.get(UiColorName::LabelText),
+            )
+        };
+
+        for &child in children {
+            let Ok((mut text, mut text_color)) = q_text.get_mut(child) else {
+                continue;
+            };
+            if text.0 != label {
+                text.0 = label.clone();
+            }
+            text_color.0 = color;
+        }
+    }
+}
+
+fn handle_reinforce_click(
+    q_buttons: Query<(&Interaction, &ReinforceButton), Changed<Interaction>>,
+    mut commands: Commands,
+) {
+    for (interaction, button) in &q_buttons {
+        if *interaction == Interaction::Pressed {
+            commands.trigger(ReinforceSquad {
+                squad: button.squad,
+            });
+        }
+    }
+}
@@ -1,7 +1,5 @@
 use crate::game_manager::balance::{UnitRow, UnitStatsCache};
-use crate::game_manager::ui::prepare_state::bottom_middle::{
-    ArcherButtonMarker, ShieldButtonMarker, SpearButtonMarker,
-};
+use crate::game_manager::ui::prepare_state::bottom_middle::UnitButton;
 use crate::game_manager::ui::prepare_state::root::PrepareUiSets;
 use crate::prelude::*;
 use bevy::sprite::Anchor;
@@ -183,22 +181,11 @@ fn add_tooltips_to_unit_buttons(
     cache: Res<UnitStatsCache>,
     palette: Res<ColorPalette>,
     asset_server: Res<AssetServer>,
-    shield_q: Query<Entity, With<ShieldButtonMarker>>,
-    archer_q: Query<Entity, With<ArcherButtonMarker>>,
-    spear_q: Query<Entity, With<SpearButtonMarker>>,
+    button_q: Query<(Entity, &UnitButton)>,
 ) {
-    let buttons: [(&str, Option<Entity>); 3] = [
-        ("Shield", shield_q.single().ok()),
-        ("Archer", archer_q.single().ok()),
-        ("Spear", spear_q.single().ok()),
-    ];
-
-    for (unit_id, maybe_entity) in buttons {
-        let Some(entity) = maybe_entity else {
-            continue;
-        };
-        let Some(row) = cache.stats.get(unit_id) else {
-            warn!("No stats found for unit '{}'", unit_id);
+    for (entity, button) in &button_q {
+        let Some(row) = cache.stats.get(button.0.as_ref()) else {
+            warn!("No stats found for unit '{}'", button.0);
             continue;
         };
 
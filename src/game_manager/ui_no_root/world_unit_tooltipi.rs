@@ -11,6 +11,9 @@ pub(crate) fn plugin(app: &mut bevy::app::App) {
             add_hover_observers_to_units,
             update_panel_text,
             update_buffs_text,
+            update_modifiers_text,
+            update_damage_text,
+            update_veterancy_text,
         ),
     );
 }
@@ -47,6 +50,18 @@ struct SpeedTextMarker;
 #[derive(Component, Default)]
 struct BuffsTextMarker;
 
+/// Marker for the stat modifiers text element
+#[derive(Component, Default)]
+struct ModifiersTextMarker;
+
+/// Marker for the damage type, resistances and last hit text element
+#[derive(Component, Default)]
+struct DamageTypeTextMarker;
+
+/// Marker for the squad's veterancy rank and progress text element
+#[derive(Component, Default)]
+struct VeterancyTextMarker;
+
 fn add_hover_observers_to_units(
     q_main_mesh: Query<(Entity, &BelongTo), Added<MainMesh>>,
     q_unit: Query<&Unit>,
@@ -146,6 +161,18 @@ fn on_hover_start(
                 HealthTextMarker,
             ));
 
+            // Squad veterancy rank and progress
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(palette.tan_light),
+                VeterancyTextMarker,
+            ));
+
             // Stats row (damage and defense)
             parent
                 .spawn(Node {
@@ -195,13 +222,37 @@ fn on_hover_start(
             parent.spawn((
                 Text::new("-"),
                 TextFont {
-                    font,
+                    font: font.clone(),
                     font_size: 11.0, // HIG: 11pt Caption (minimum readable, was 10pt)
                     ..default()
                 },
                 TextColor(Color::srgb(0.8, 0.6, 1.0)), // #cc99ff
                 BuffsTextMarker,
             ));
+
+            // Stat modifiers text (terrain and the like)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.6)), // #ffe699
+                ModifiersTextMarker,
+            ));
+
+            // Damage type, resistances and how the last hit was worked out
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font,
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.85)), // #d9d9d9
+                DamageTypeTextMarker,
+            ));
         });
 }
 
@@ -277,13 +328,14 @@ fn update_panel_text(
 /// Update buffs text every frame (buffs can change dynamically)
 fn update_buffs_text(
     q_panels: Query<&PanelForUnit, With<UnitHealthPanel>>,
-    q_buffs: Query<&ActiveBuffs>,
+    q_buffs: Query<&ActiveEffects>,
+    registry: Res<EffectRegistry>,
     mut q_buffs_text: Query<&mut Text, With<BuffsTextMarker>>,
 ) {
     for panel_for in &q_panels {
         // Get the buffs for this unit
-        let buff_text = if let Ok(buffs) = q_buffs.get(panel_for.0) {
-            format_buffs(&buffs.list)
+        let buff_text = if let Ok(effects) = q_buffs.get(panel_for.0) {
+            format_buffs(&effects.list, &registry)
         } else {
             String::new()
         };
@@ -296,49 +348,129 @@ fn update_buffs_text(
     }
 }
 
-fn format_buffs(buffs: &[BuffEffect]) -> String {
-    if buffs.is_empty() {
-        return String::new();
+/// Update modifiers text every frame (units step on and off terrain, timed modifiers wear off)
+fn update_modifiers_text(
+    q_panels: Query<&PanelForUnit, With<UnitHealthPanel>>,
+    q_modifiers: Query<(&StatModifiers, &BaseCombatAttributes, &CombatAttributes)>,
+    mut q_modifiers_text: Query<&mut Text, With<ModifiersTextMarker>>,
+) {
+    for panel_for in &q_panels {
+        let modifiers_text = q_modifiers
+            .get(panel_for.0)
+            .map(|(modifiers, base, stats)| format_modifiers(modifiers, &base.0, stats))
+            .unwrap_or_default();
+
+        for mut text in &mut q_modifiers_text {
+            if **text != modifiers_text {
+                **text = modifiers_text.clone();
+            }
+        }
     }
+}
 
-    let buff_strs: Vec<String> = buffs
-        .iter()
-        .map(|buff| match buff {
-            BuffEffect::Poison(data) => {
-                // Poison deals stacks damage per tick, then decrements
-                // Total damage = n + (n-1) + (n-2) + ... + 1 = n*(n+1)/2
-                let total_damage = data.stacks * (data.stacks + 1) / 2;
-                format!(
-                    "Poison x{} ({} total dmg, {}\u{2192}1/sec)",
-                    data.stacks, total_damage, data.stacks
-                )
-            }
-            BuffEffect::Block(data) => {
-                let block_chance = data.current_stacks as f32 * 5.0;
-                format!(
-                    "Block {}/{} ({:.0}% chance)",
-                    data.current_stacks, data.max_stacks, block_chance
-                )
+/// Update damage text every frame (the last hit changes with every blow)
+fn update_damage_text(
+    q_panels: Query<&PanelForUnit, With<UnitHealthPanel>>,
+    q_damage: Query<(&CombatAttributes, Option<&LastHitTaken>)>,
+    mut q_damage_text: Query<&mut Text, With<DamageTypeTextMarker>>,
+) {
+    for panel_for in &q_panels {
+        let damage_text = q_damage
+            .get(panel_for.0)
+            .map(|(stats, last_hit)| format_damage(stats, last_hit))
+            .unwrap_or_default();
+
+        for mut text in &mut q_damage_text {
+            if **text != damage_text {
+                **text = damage_text.clone();
             }
-            BuffEffect::Stun(data) => {
-                let stun_chance = data.current_stacks as f32 * 5.0;
-                format!(
-                    "Stun {}/{} ({:.0}% chance)",
-                    data.current_stacks, data.max_stacks, stun_chance
-                )
+        }
+    }
+}
+
+/// Update veterancy text every frame (squads earn experience with every blow)
+fn update_veterancy_text(
+    q_panels: Query<&PanelForUnit, With<UnitHealthPanel>>,
+    q_belong: Query<&BelongToSquad>,
+    q_veterancy: Query<&Veterancy>,
+    mut q_veterancy_text: Query<&mut Text, With<VeterancyTextMarker>>,
+) {
+    for panel_for in &q_panels {
+        let veterancy_text = q_belong
+            .get(panel_for.0)
+            .and_then(|belong| q_veterancy.get(belong.0))
+            .map(|veterancy| veterancy.to_string())
+            .unwrap_or_default();
+
+        for mut text in &mut q_veterancy_text {
+            if **text != veterancy_text {
+                **text = veterancy_text.clone();
             }
-            BuffEffect::AttackSpeed(data) => {
-                let speed_bonus = data.stacks as f32 * 5.0;
-                format!(
-                    "AtkSpd {}/{} ({:.0}% faster)",
-                    data.stacks, data.max_stacks, speed_bonus
-                )
+        }
+    }
+}
+
+/// e.g. `Deals Pierce | Resists Blunt -10%`, then `Last hit: 8.0 Slash -> DEF 8.0`.
+fn format_damage(stats: &CombatAttributes, last_hit: Option<&LastHitTaken>) -> String {
+    let mut text = format!("Deals {:?}", stats.damage_type);
+    if stats.resistances != Resistances::default() {
+        text += &format!(" | Resists {}", stats.resistances);
+    }
+    if let Some(last_hit) = last_hit {
+        text += &format!("\nLast hit: {}", last_hit.0);
+    }
+    text
+}
+
+/// One line per modified stat: its base and final value, then what changed it,
+/// e.g. `ATK 8 -> 10: BigEye +25% (2s)`.
+fn format_modifiers(
+    modifiers: &StatModifiers,
+    base: &CombatAttributes,
+    stats: &CombatAttributes,
+) -> String {
+    Stat::ALL
+        .into_iter()
+        .filter_map(|stat| {
+            let sources = modifiers
+                .iter()
+                .filter(|modifier| modifier.stat == stat)
+                .map(|modifier| modifier.to_string())
+                .collect::<Vec<_>>();
+            if sources.is_empty() {
+                return None;
             }
-            BuffEffect::Invincible => "Invincible".to_string(),
+            Some(format!(
+                "{} {} -> {}: {}",
+                stat.label(),
+                format_stat_value(stat.value(base)),
+                format_stat_value(stat.value(stats)),
+                sources.join(", ")
+            ))
         })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whole values stay whole; fractional ones, like attack intervals, keep a tenth.
+fn format_stat_value(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+fn format_buffs(effects: &[ActiveEffect], registry: &EffectRegistry) -> String {
+    let buff_strs: Vec<String> = effects
+        .iter()
+        .filter_map(|effect| Some(registry.get(&effect.id)?.describe(effect)))
         .collect();
+    if buff_strs.is_empty() {
+        return String::new();
+    }
 
-    format!("Buffs: {}", buff_strs.join(" | "))
+    format!("Effects: {}", buff_strs.join(" | "))
 }
 
 fn on_hover_end(
@@ -15,6 +15,7 @@ mod theme;
 pub use config::*;
 mod palette;
 mod sprite_layer;
+mod storage;
 mod third_party;
 mod ui_camera;
 use crate::prelude::*;
@@ -53,6 +54,15 @@ fn main() -> AppExit {
             println!("Feature dev_mode enabled, .env loaded.");
         }
     }
+    // `bevy_game check-balance ...` validates the balance sheets instead of starting the game.
+    if std::env::args().nth(1).as_deref() == Some("check-balance") {
+        return game_manager::run_check_command(std::env::args().skip(2));
+    }
+    // `bevy_game matchup ...` and `replay ...` run simulation tools instead of the game.
+    if let Some(exit) = game_manager::simulation::run_command() {
+        return exit;
+    }
+
     let mut app = App::new();
     // Don't panic on Bevy system errors, just log them.
     app.set_error_handler(error);
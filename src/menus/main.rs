@@ -20,6 +20,7 @@ fn spawn_main_menu(
     mut commands: Commands,
     palette: Res<ColorPalette>,
     progress: Res<GameProgress>,
+    saved_run: Res<SavedRun>,
     asset_server: Res<AssetServer>,
 ) {
     let play_label = format!("Night {}", progress.current_round);
@@ -28,37 +29,69 @@ fn spawn_main_menu(
     } else {
         play_label
     };
-    commands.spawn((
-        widget::ui_root("Main Menu"),
-        BackgroundGradient::from(LinearGradient {
-            angle: LinearGradient::TO_BOTTOM,
-            stops: vec![
-                // Slightly lighter brown_dark at top (#867b85 = brown_dark lightened 25%)
-                ColorStop::auto(Color::srgb_u8(0x86, 0x7b, 0x85)),
-                // Pure brown_dark at bottom
-                ColorStop::auto(palette.brown_dark),
+    let menu = commands
+        .spawn((
+            widget::ui_root("Main Menu"),
+            BackgroundGradient::from(LinearGradient {
+                angle: LinearGradient::TO_BOTTOM,
+                stops: vec![
+                    // Slightly lighter brown_dark at top (#867b85 = brown_dark lightened 25%)
+                    ColorStop::auto(Color::srgb_u8(0x86, 0x7b, 0x85)),
+                    // Pure brown_dark at bottom
+                    ColorStop::auto(palette.brown_dark),
+                ],
+                ..default()
+            }),
+            GlobalZIndex(2),
+            DespawnOnExit(Menu::Main),
+            #[cfg(not(target_family = "wasm"))]
+            children![
+                widget::button(play_label, enter_loading_screen, &palette, &asset_server),
+                widget::button("Settings", open_settings_menu, &palette, &asset_server),
+                widget::button("Credits", open_credits_menu, &palette, &asset_server),
+                widget::button("Exit", exit_app, &palette, &asset_server),
             ],
-            ..default()
-        }),
-        GlobalZIndex(2),
-        DespawnOnExit(Menu::Main),
-        #[cfg(not(target_family = "wasm"))]
-        children![
-            widget::button(play_label, enter_loading_screen, &palette, &asset_server),
-            widget::button("Settings", open_settings_menu, &palette, &asset_server),
-            widget::button("Credits", open_credits_menu, &palette, &asset_server),
-            widget::button("Exit", exit_app, &palette, &asset_server),
-        ],
-        #[cfg(target_family = "wasm")]
-        children![
-            widget::button(play_label, enter_loading_screen, &palette, &asset_server),
-            widget::button("Settings", open_settings_menu, &palette, &asset_server),
-            widget::button("Credits", open_credits_menu, &palette, &asset_server),
-        ],
-    ));
+            #[cfg(target_family = "wasm")]
+            children![
+                widget::button(play_label, enter_loading_screen, &palette, &asset_server),
+                widget::button("Settings", open_settings_menu, &palette, &asset_server),
+                widget::button("Credits", open_credits_menu, &palette, &asset_server),
+            ],
+        ))
+        .id();
+
+    if let Some(run) = &saved_run.0 {
+        let continue_label = format!("Continue Night {}", run.progress.current_round);
+        let continue_button = commands
+            .spawn(widget::button(
+                continue_label,
+                continue_saved_run,
+                &palette,
+                &asset_server,
+            ))
+            .id();
+        commands.entity(menu).insert_children(0, &[continue_button]);
+    }
 }
 
-fn enter_loading_screen(_on: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
+fn continue_saved_run(
+    _on: On<Pointer<Click>>,
+    mut commands: Commands,
+    saved_run: Res<SavedRun>,
+    mut progress: ResMut<GameProgress>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    saved_run.resume(&mut commands, &mut progress);
+    next_screen.set(Screen::Loading);
+}
+
+/// Starts a fresh run, so the saved one can't be continued anymore.
+fn enter_loading_screen(
+    _on: On<Pointer<Click>>,
+    mut saved_run: ResMut<SavedRun>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    saved_run.clear();
     next_screen.set(Screen::Loading);
 }
 
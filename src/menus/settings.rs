@@ -15,16 +15,14 @@ use crate::{
     Pause,
     menus::Menu,
     screens::Screen,
+    storage,
     theme::prelude::*,
 };
 #[cfg(feature = "backend")]
 use crate::game_manager::audio::{DEFAULT_MAIN_VOLUME, perceptual::PerceptualVolumeConverter};
 
 pub(super) fn plugin(app: &mut App) {
-    #[cfg(feature = "backend")]
-    app.init_resource::<VolumeSliderSettings>();
-    app.init_resource::<VsyncSetting>();
-    app.init_resource::<FpsLimiterSettings>();
+    load_settings().insert_resources(app);
     app.add_systems(OnEnter(Menu::Settings), spawn_settings_menu);
     app.add_systems(
         Update,
@@ -34,17 +32,26 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         (
-            update_global_volume.run_if(resource_exists_and_changed::<VolumeSliderSettings>),
-            update_volume_label,
-        )
-            .run_if(in_state(Menu::Settings)),
+            // The main bus only appears once audio is up, so apply the loaded volume then too.
+            update_global_volume.run_if(
+                resource_exists_and_changed::<VolumeSliderSettings>
+                    .or(any_match_filter::<Added<MainBus>>),
+            ),
+            update_volume_label.run_if(in_state(Menu::Settings)),
+        ),
     );
     app.add_systems(
         Update,
         (
             update_vsync.run_if(resource_exists_and_changed::<VsyncSetting>),
-            update_vsync_label,
             update_fps_limiter.run_if(resource_exists_and_changed::<FpsLimiterSettings>),
+            save_settings.run_if(settings_changed),
+        ),
+    );
+    app.add_systems(
+        Update,
+        (
+            update_vsync_label,
             update_fps_limiter_enabled_label,
             update_fps_limiter_target_label,
             handle_settings_menu_button_hover,
@@ -150,7 +157,7 @@ fn spawn_settings_menu(
 }
 
 #[cfg(feature = "backend")]
-#[derive(Resource, Reflect, Debug)]
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
 struct VolumeSliderSettings(usize);
 
 #[cfg(feature = "backend")]
@@ -224,7 +231,7 @@ fn update_volume_label(
 #[reflect(Component)]
 struct CameraSensitivityLabel;
 
-#[derive(Resource, Reflect, Debug)]
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
 struct VsyncSetting(bool);
 
 impl Default for VsyncSetting {
@@ -257,7 +264,7 @@ fn update_vsync_label(mut label: Single<&mut Text, With<VsyncLabel>>, setting: R
     label.0 = if setting.0 { "On".into() } else { "Off".into() };
 }
 
-#[derive(Resource, Reflect, Debug)]
+#[derive(Resource, Reflect, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 struct FpsLimiterSettings {
     enabled: bool,
     target_fps: u32,
@@ -336,6 +343,139 @@ fn update_fps_limiter_target_label(
     label.0 = format!("{}", settings.target_fps);
 }
 
+// ============================================================================
+// Settings file
+// ============================================================================
+
+/// Storage key of the settings file: `settings.ron` on native, a `localStorage` entry on web.
+const SETTINGS_KEY: &str = "settings.ron";
+
+/// Bumped whenever a field is renamed or changes meaning; see [`SettingsFile::migrate`].
+const SETTINGS_VERSION: u32 = 1;
+
+/// Everything the settings menu remembers between launches.
+///
+/// New options (keybinds, a colorblind palette, ...) only need a field here:
+/// `#[serde(default)]` fills it in when an older file doesn't have it.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[serde(default)]
+struct SettingsFile {
+    /// Missing in files written before settings were versioned, which reads as 0.
+    #[serde(default)]
+    version: u32,
+    /// Volume slider ticks; `None` in builds without audio.
+    volume_ticks: Option<usize>,
+    vsync: bool,
+    fps_limiter: FpsLimiterSettings,
+}
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            #[cfg(feature = "backend")]
+            volume_ticks: Some(VolumeSliderSettings::default().0),
+            #[cfg(not(feature = "backend"))]
+            volume_ticks: None,
+            vsync: VsyncSetting::default().0,
+            fps_limiter: FpsLimiterSettings::default(),
+        }
+    }
+}
+
+/// The volume in the settings file, kept by builds without audio so saving doesn't wipe
+/// the one a build with audio left there.
+#[cfg(not(feature = "backend"))]
+#[derive(Resource)]
+struct KeptVolumeTicks(Option<usize>);
+
+impl SettingsFile {
+    /// Brings a file written by an older build up to [`SETTINGS_VERSION`].
+    fn migrate(mut self) -> Self {
+        // Version 0 has the same layout as version 1. Later steps go here, oldest first:
+        // `if self.version < 2 { ... }`.
+        self.version = SETTINGS_VERSION;
+        self
+    }
+
+    fn insert_resources(self, app: &mut App) {
+        #[cfg(feature = "backend")]
+        app.insert_resource(
+            self.volume_ticks
+                .map(|ticks| VolumeSliderSettings(ticks.min(VolumeSliderSettings::MAX_TICK_COUNT)))
+                .unwrap_or_default(),
+        );
+        #[cfg(not(feature = "backend"))]
+        app.insert_resource(KeptVolumeTicks(self.volume_ticks));
+        app.insert_resource(VsyncSetting(self.vsync));
+        app.insert_resource(self.fps_limiter);
+    }
+}
+
+fn load_settings() -> SettingsFile {
+    let source = match storage::read(SETTINGS_KEY) {
+        Ok(Some(source)) => source,
+        Ok(None) => return SettingsFile::default(),
+        Err(error) => {
+            warn!("[Settings] Could not read settings: {error:#}");
+            return SettingsFile::default();
+        }
+    };
+
+    match ron::from_str::<SettingsFile>(&source) {
+        Ok(file) if file.version > SETTINGS_VERSION => {
+            warn!(
+                "[Settings] Settings version {} is newer than this build, using defaults",
+                file.version
+            );
+            SettingsFile::default()
+        }
+        Ok(file) => file.migrate(),
+        Err(error) => {
+            warn!("[Settings] Ignoring unreadable settings: {error}");
+            SettingsFile::default()
+        }
+    }
+}
+
+/// Whether the player changed a setting; loading them at startup doesn't count.
+fn settings_changed(
+    #[cfg(feature = "backend")] volume: Res<VolumeSliderSettings>,
+    vsync: Res<VsyncSetting>,
+    fps_limiter: Res<FpsLimiterSettings>,
+) -> bool {
+    #[cfg(feature = "backend")]
+    if volume.is_changed() && !volume.is_added() {
+        return true;
+    }
+    (vsync.is_changed() && !vsync.is_added())
+        || (fps_limiter.is_changed() && !fps_limiter.is_added())
+}
+
+/// Writes the settings back whenever one of them changes.
+fn save_settings(
+    #[cfg(feature = "backend")] volume: Res<VolumeSliderSettings>,
+    #[cfg(not(feature = "backend"))] kept_volume: Res<KeptVolumeTicks>,
+    vsync: Res<VsyncSetting>,
+    fps_limiter: Res<FpsLimiterSettings>,
+) {
+    let file = SettingsFile {
+        version: SETTINGS_VERSION,
+        #[cfg(feature = "backend")]
+        volume_ticks: Some(volume.0),
+        #[cfg(not(feature = "backend"))]
+        volume_ticks: kept_volume.0,
+        vsync: vsync.0,
+        fps_limiter: *fps_limiter,
+    };
+    let result = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+        .map_err(anyhow::Error::from)
+        .and_then(|source| storage::write(SETTINGS_KEY, &source));
+    if let Err(error) = result {
+        warn!("[Settings] Could not save settings: {error:#}");
+    }
+}
+
 fn go_back_on_click(
     _on: On<Pointer<Click>>,
     screen: Res<State<Screen>>,
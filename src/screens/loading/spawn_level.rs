@@ -32,6 +32,7 @@ fn advance_to_gameplay_screen(
     game_assets: Res<SceneAssets>,
     ldtk_projects: Res<Assets<LdtkProject>>,
     balance: Res<UnitBalanceAssets>,
+    balance_issues: Res<BalanceIssues>,
 ) {
     if !asset_server.is_loaded_with_dependencies(&game_assets.level) {
         return;
@@ -43,6 +44,10 @@ fn advance_to_gameplay_screen(
     if ldtk_projects.get(&game_assets.level).is_none() {
         return;
     }
+    // Dev builds wait here so balance problems get fixed before anyone plays on them.
+    if cfg!(feature = "dev") && !balance_issues.0.is_empty() {
+        return;
+    }
 
     next_screen.set(Screen::Gameplay);
 }
@@ -0,0 +1,89 @@
+//! Small key/value store for save data.
+//!
+//! Native builds keep one `<key>.json` file per key in the platform data directory
+//! (e.g. `~/.local/share/just-let-me-sleep` on Linux); a key with its own extension, such as
+//! `settings.ron`, is used as the file name as is. Web builds use `localStorage`,
+//! which survives a tab refresh on itch.io.
+
+#[cfg(not(target_family = "wasm"))]
+use std::path::PathBuf;
+
+#[cfg(target_family = "wasm")]
+const KEY_PREFIX: &str = "just-let-me-sleep/";
+
+/// Reads the value stored under `key`, or `None` if nothing was saved yet.
+pub(crate) fn read(key: &str) -> anyhow::Result<Option<String>> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        match std::fs::read_to_string(path(key)?) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        local_storage()?
+            .get_item(&format!("{KEY_PREFIX}{key}"))
+            .map_err(|error| anyhow::anyhow!("localStorage read failed: {error:?}"))
+    }
+}
+
+/// Stores `contents` under `key`, replacing any previous value.
+pub(crate) fn write(key: &str, contents: &str) -> anyhow::Result<()> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let path = path(key)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so a crash mid-write can't corrupt the old save.
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        std::fs::write(&temp, contents)?;
+        std::fs::rename(&temp, &path)?;
+        Ok(())
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        local_storage()?
+            .set_item(&format!("{KEY_PREFIX}{key}"), contents)
+            .map_err(|error| anyhow::anyhow!("localStorage write failed: {error:?}"))
+    }
+}
+
+/// Deletes the value stored under `key`, if any.
+pub(crate) fn remove(key: &str) -> anyhow::Result<()> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        match std::fs::remove_file(path(key)?) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        local_storage()?
+            .remove_item(&format!("{KEY_PREFIX}{key}"))
+            .map_err(|error| anyhow::anyhow!("localStorage remove failed: {error:?}"))
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn path(key: &str) -> anyhow::Result<PathBuf> {
+    let dirs = directories::ProjectDirs::from("io", "rockcen", "just-let-me-sleep")
+        .ok_or_else(|| anyhow::anyhow!("no home directory to save into"))?;
+    let file_name = if std::path::Path::new(key).extension().is_some() {
+        key.to_string()
+    } else {
+        format!("{key}.json")
+    };
+    Ok(dirs.data_dir().join(file_name))
+}
+
+#[cfg(target_family = "wasm")]
+fn local_storage() -> anyhow::Result<web_sys::Storage> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or_else(|| anyhow::anyhow!("localStorage is not available"))
+}